@@ -0,0 +1,1085 @@
+use core::fmt;
+use std::boxed::Box;
+use std::io::{BufRead, Write};
+use std::string::String;
+use std::vec::Vec;
+
+use crate::*;
+
+/// An optional stream backing a device.
+///
+/// Streams can be neither cloned nor debugged, so this only shows
+/// whether a stream is present.
+struct Backing<T: ?Sized>(Option<Box<T>>);
+
+impl<T: ?Sized> Default for Backing<T> {
+    fn default() -> Self {
+        Backing(None)
+    }
+}
+
+impl Backing<dyn BufRead> {
+    /// Read the next line into `lines`, if the stream has one.
+    ///
+    /// The stream is dropped once exhausted.
+    fn pull(&mut self, lines: &mut Vec<String>) -> Result<(), ()> {
+        let Some(reader) = self.0.as_mut() else {
+            return Ok(());
+        };
+        let mut line = String::new();
+        if reader.read_line(&mut line).map_err(|_| ())? == 0 {
+            self.0 = None;
+            return Ok(());
+        }
+        let len = line.trim_end_matches(['\r', '\n']).len();
+        line.truncate(len);
+        lines.push(line);
+        Ok(())
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Backing<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(_) => f.write_str("Some(..)"),
+            None => f.write_str("None"),
+        }
+    }
+}
+
+/// A magnetic tape unit for [`MixVM`], holding its content in memory.
+///
+/// Tapes are units `0` to `7` in a standard MIX installation. Each
+/// block contains [`TapeDevice::BLOCK_SIZE`] words. Writing a block
+/// at the end of the tape extends it.
+///
+/// # Control commands
+/// * `M == 0` - Rewind the tape.
+/// * `M < 0` - Skip backward `-M` blocks, stopping at the beginning.
+/// * `M > 0` - Skip forward `M` blocks.
+///
+/// # Example
+/// ```rust
+/// use mixture::*;
+///
+/// let mut tape = TapeDevice::new();
+/// let block = [FullWord::from_i64(42).0; TapeDevice::BLOCK_SIZE];
+///
+/// tape.write(&block).unwrap();
+/// assert_eq!(tape.position(), 1);
+/// tape.control(0).unwrap();
+/// assert_eq!(tape.position(), 0);
+/// ```
+///
+/// [`MixVM`]: crate::MixVM
+#[derive(Clone, Debug, Default)]
+pub struct TapeDevice {
+    /// The words on the tape.
+    words: Vec<FullWord>,
+
+    /// The index of the block under the head.
+    position: usize,
+}
+
+impl TapeDevice {
+    /// Number of words in a tape block.
+    pub const BLOCK_SIZE: usize = 100;
+
+    /// Create a new empty tape.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new tape holding the given words.
+    ///
+    /// # Arguments
+    /// * `words` - The initial content of the tape.
+    pub fn from_words(words: Vec<FullWord>) -> Self {
+        TapeDevice { words, position: 0 }
+    }
+
+    /// Get the words on the tape.
+    pub fn words(&self) -> &[FullWord] {
+        &self.words
+    }
+
+    /// Get the index of the block under the head.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl IODevice for TapeDevice {
+    fn read(&mut self, buffer: &mut [FullWord]) -> Result<(), ()> {
+        if buffer.len() != Self::BLOCK_SIZE {
+            return Err(());
+        }
+        let start = self.position * Self::BLOCK_SIZE;
+        let block = self.words.get(start..start + Self::BLOCK_SIZE).ok_or(())?;
+        buffer.copy_from_slice(block);
+        self.position += 1;
+        Ok(())
+    }
+
+    fn write(&mut self, data: &[FullWord]) -> Result<(), usize> {
+        if data.len() != Self::BLOCK_SIZE {
+            return Err(0);
+        }
+        let start = self.position * Self::BLOCK_SIZE;
+        let end = start + Self::BLOCK_SIZE;
+        if self.words.len() < end {
+            self.words.resize(end, FullWord::new());
+        }
+        self.words[start..end].copy_from_slice(data);
+        self.position += 1;
+        Ok(())
+    }
+
+    fn control(&mut self, command: i16) -> Result<(), ()> {
+        let count = command.unsigned_abs() as usize;
+        self.position = match command {
+            0 => 0,
+            c if c < 0 => self.position.saturating_sub(count),
+            _ => self.position + count,
+        };
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.position = 0;
+    }
+
+    fn is_busy(&self) -> Result<bool, ()> {
+        Ok(false)
+    }
+
+    fn is_ready(&self) -> Result<bool, ()> {
+        Ok(true)
+    }
+
+    fn get_block_size(&self) -> usize {
+        Self::BLOCK_SIZE
+    }
+}
+
+/// A line printer for [`MixVM`], collecting printed lines in memory.
+///
+/// The printer is unit `18` in a standard MIX installation. Each
+/// block contains [`PrinterDevice::BLOCK_SIZE`] words, printed as
+/// a line of characters in [`Alphabet`].
+///
+/// Lines are kept in memory, unless a writer is set with
+/// [`PrinterDevice::with_writer()`]. Lines are then written to it
+/// instead, each followed by a newline, and a new page is started
+/// with a form feed.
+///
+/// # Control commands
+/// * `M == 0` - Skip to the top of the next page.
+///
+/// # Example
+/// ```rust
+/// use mixture::*;
+///
+/// let mut printer = PrinterDevice::new();
+/// let block = [FullWord::from_bytes([0, 1, 2, 3, 4, 5]); PrinterDevice::BLOCK_SIZE];
+///
+/// printer.write(&block).unwrap();
+/// assert!(printer.pages()[0][0].starts_with("ABCDE"));
+/// printer.control(0).unwrap();
+/// assert_eq!(printer.pages().len(), 2);
+/// ```
+///
+/// [`MixVM`]: crate::MixVM
+#[derive(Debug)]
+pub struct PrinterDevice {
+    /// The printed pages, each comprised of lines.
+    pages: Vec<Vec<String>>,
+
+    /// The writer receiving printed lines instead of the pages.
+    writer: Backing<dyn Write>,
+}
+
+impl PrinterDevice {
+    /// Number of words in a printer block, i.e. a line.
+    pub const BLOCK_SIZE: usize = 24;
+
+    /// Create a new printer with a blank page.
+    pub fn new() -> Self {
+        PrinterDevice {
+            pages: std::vec![Vec::new()],
+            writer: Backing(None),
+        }
+    }
+
+    /// Print to the given writer instead of keeping pages in memory.
+    ///
+    /// # Arguments
+    /// * `writer` - The writer receiving printed lines.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut printer = PrinterDevice::new().with_writer(Box::new(std::io::sink()));
+    /// let block = [FullWord::from_bytes([0, 1, 2, 3, 4, 5]); PrinterDevice::BLOCK_SIZE];
+    ///
+    /// printer.write(&block).unwrap();
+    /// assert!(printer.lines().next().is_none());
+    /// ```
+    pub fn with_writer(self, writer: Box<dyn Write>) -> Self {
+        PrinterDevice {
+            writer: Backing(Some(writer)),
+            ..self
+        }
+    }
+
+    /// Get the printed pages.
+    ///
+    /// The last page is the one currently being printed.
+    pub fn pages(&self) -> &[Vec<String>] {
+        &self.pages
+    }
+
+    /// Get all printed lines, regardless of pages.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.pages.iter().flatten().map(|line| line.as_str())
+    }
+}
+
+impl Default for PrinterDevice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IODevice for PrinterDevice {
+    fn read(&mut self, _: &mut [FullWord]) -> Result<(), ()> {
+        // A printer never reads.
+        Err(())
+    }
+
+    fn write(&mut self, data: &[FullWord]) -> Result<(), usize> {
+        if data.len() != Self::BLOCK_SIZE {
+            return Err(0);
+        }
+        let line = decode_line(data)?;
+        if let Some(writer) = self.writer.0.as_mut() {
+            return writeln!(writer, "{line}").map_err(|_| 0);
+        }
+        // There is always a page being printed.
+        if let Some(page) = self.pages.last_mut() {
+            page.push(line);
+        }
+        Ok(())
+    }
+
+    fn control(&mut self, command: i16) -> Result<(), ()> {
+        match command {
+            0 => {
+                if let Some(writer) = self.writer.0.as_mut() {
+                    return writer.write_all(b"\x0c").map_err(|_| ());
+                }
+                self.pages.push(Vec::new());
+                Ok(())
+            }
+            _ => Err(()),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), ()> {
+        match self.writer.0.as_mut() {
+            Some(writer) => writer.flush().map_err(|_| ()),
+            None => Ok(()),
+        }
+    }
+
+    fn is_busy(&self) -> Result<bool, ()> {
+        Ok(false)
+    }
+
+    fn is_ready(&self) -> Result<bool, ()> {
+        Ok(true)
+    }
+
+    fn get_block_size(&self) -> usize {
+        Self::BLOCK_SIZE
+    }
+}
+
+/// A magnetic disk or drum unit for [`MixVM`], holding its content
+/// in memory.
+///
+/// Disks are units `8` to `15` in a standard MIX installation. Each
+/// block contains [`DiskDevice::BLOCK_SIZE`] words. Unlike tapes,
+/// blocks can be accessed in any order. A disk holds at most
+/// [`DiskDevice::capacity()`] blocks, [`DiskDevice::DEFAULT_CAPACITY`]
+/// unless set with [`DiskDevice::with_capacity()`].
+///
+/// The block address is taken from `rX` through [`IODevice::seek()`]
+/// when the disk is installed as units `8` to `15`.
+///
+/// # Control commands
+/// * `M == 0` - Move to the block given by `rX`.
+///
+/// # Example
+/// ```rust
+/// use mixture::*;
+///
+/// let mut disk = DiskDevice::new();
+/// let block = [FullWord::from_i64(42).0; DiskDevice::BLOCK_SIZE];
+///
+/// disk.seek(3).unwrap();
+/// disk.write(&block).unwrap();
+/// assert_eq!(disk.words().len(), 4 * DiskDevice::BLOCK_SIZE);
+/// ```
+///
+/// [`MixVM`]: crate::MixVM
+#[derive(Clone, Debug)]
+pub struct DiskDevice {
+    /// The words on the disk.
+    words: Vec<FullWord>,
+
+    /// The index of the block to access.
+    position: usize,
+
+    /// The count of blocks on the disk.
+    capacity: usize,
+}
+
+impl DiskDevice {
+    /// Number of words in a disk block.
+    pub const BLOCK_SIZE: usize = 100;
+
+    /// Default count of blocks on a disk.
+    pub const DEFAULT_CAPACITY: usize = 4096;
+
+    /// Create a new empty disk.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new disk holding the given words.
+    ///
+    /// # Arguments
+    /// * `words` - The initial content of the disk.
+    pub fn from_words(words: Vec<FullWord>) -> Self {
+        DiskDevice {
+            words,
+            ..Default::default()
+        }
+    }
+
+    /// Set the count of blocks on the disk.
+    ///
+    /// Seeking to a block beyond the capacity fails.
+    ///
+    /// # Arguments
+    /// * `blocks` - The count of blocks.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut disk = DiskDevice::new().with_capacity(4);
+    ///
+    /// assert!(disk.seek(3).is_ok());
+    /// assert!(disk.seek(4).is_err());
+    /// ```
+    pub fn with_capacity(self, blocks: usize) -> Self {
+        DiskDevice {
+            capacity: blocks,
+            ..self
+        }
+    }
+
+    /// Get the count of blocks on the disk.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the words on the disk.
+    pub fn words(&self) -> &[FullWord] {
+        &self.words
+    }
+}
+
+impl Default for DiskDevice {
+    fn default() -> Self {
+        DiskDevice {
+            words: Vec::new(),
+            position: 0,
+            capacity: Self::DEFAULT_CAPACITY,
+        }
+    }
+}
+
+impl IODevice for DiskDevice {
+    fn read(&mut self, buffer: &mut [FullWord]) -> Result<(), ()> {
+        if buffer.len() != Self::BLOCK_SIZE || self.position >= self.capacity {
+            return Err(());
+        }
+        let start = self.position * Self::BLOCK_SIZE;
+        // Blocks never written read as zeros.
+        buffer.fill(FullWord::new());
+        if let Some(block) = self.words.get(start..) {
+            let len = block.len().min(Self::BLOCK_SIZE);
+            buffer[..len].copy_from_slice(&block[..len]);
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, data: &[FullWord]) -> Result<(), usize> {
+        if data.len() != Self::BLOCK_SIZE || self.position >= self.capacity {
+            return Err(0);
+        }
+        let start = self.position * Self::BLOCK_SIZE;
+        let end = start + Self::BLOCK_SIZE;
+        if self.words.len() < end {
+            self.words.resize(end, FullWord::new());
+        }
+        self.words[start..end].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn control(&mut self, command: i16) -> Result<(), ()> {
+        // The block is already set by seek().
+        match command {
+            0 => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    fn seek(&mut self, position: i64) -> Result<(), ()> {
+        let position = usize::try_from(position).map_err(|_| ())?;
+        if position >= self.capacity {
+            return Err(());
+        }
+        self.position = position;
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.position = 0;
+    }
+
+    fn is_busy(&self) -> Result<bool, ()> {
+        Ok(false)
+    }
+
+    fn is_ready(&self) -> Result<bool, ()> {
+        Ok(true)
+    }
+
+    fn get_block_size(&self) -> usize {
+        Self::BLOCK_SIZE
+    }
+}
+
+/// A card reader for [`MixVM`], reading cards of text in [`Alphabet`].
+///
+/// The card reader is unit `16` in a standard MIX installation.
+/// Each card is a block of [`CardReaderDevice::BLOCK_SIZE`] words.
+/// Short cards are padded with spaces, unless disabled with
+/// [`CardReaderDevice::with_pad_short_reads()`]. More cards can be
+/// read line by line from a stream set with
+/// [`CardReaderDevice::with_reader()`]. The reader is at end of input
+/// once all cards are read.
+///
+/// # Example
+/// ```rust
+/// use mixture::*;
+///
+/// let mut reader = CardReaderDevice::new(vec!["HELLO".to_string()]);
+/// let mut block = [FullWord::new(); CardReaderDevice::BLOCK_SIZE];
+///
+/// reader.read(&mut block).unwrap();
+/// assert_eq!(block[0][..], [0, 8, 5, 13, 13, 16]);
+/// assert!(reader.read(&mut block).is_err());
+/// ```
+///
+/// [`MixVM`]: crate::MixVM
+#[derive(Debug)]
+pub struct CardReaderDevice {
+    /// The cards in the hopper.
+    cards: Vec<String>,
+
+    /// The stream supplying cards after those in the hopper.
+    reader: Backing<dyn BufRead>,
+
+    /// The index of the next card.
+    position: usize,
+
+    /// Whether short cards are padded with spaces instead of
+    /// failing to read.
+    pad_short_reads: bool,
+}
+
+impl CardReaderDevice {
+    /// Number of words on a card.
+    pub const BLOCK_SIZE: usize = 16;
+
+    /// Create a new card reader with the given cards.
+    ///
+    /// # Arguments
+    /// * `cards` - The cards to read, in order.
+    pub fn new(cards: Vec<String>) -> Self {
+        CardReaderDevice {
+            cards,
+            reader: Backing(None),
+            position: 0,
+            pad_short_reads: true,
+        }
+    }
+
+    /// Read cards from the given stream, one per line, after those
+    /// already in the hopper.
+    ///
+    /// # Arguments
+    /// * `reader` - The stream supplying cards.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let cards = std::io::Cursor::new("HELLO\nWORLD\n");
+    /// let mut reader = CardReaderDevice::new(vec![]).with_reader(Box::new(cards));
+    /// let mut block = [FullWord::new(); CardReaderDevice::BLOCK_SIZE];
+    ///
+    /// reader.read(&mut block).unwrap();
+    /// reader.read(&mut block).unwrap();
+    /// assert_eq!(block[0][..], [0, 26, 16, 19, 13, 4]);
+    /// assert!(reader.at_eof());
+    /// ```
+    pub fn with_reader(self, reader: Box<dyn BufRead>) -> Self {
+        let mut reader = CardReaderDevice {
+            reader: Backing(Some(reader)),
+            ..self
+        };
+        // Look ahead so that the end of input is known. Errors show up
+        // on the next read.
+        let _ = reader.helper_pull();
+        reader
+    }
+
+    /// Set whether short cards are padded with spaces.
+    ///
+    /// Padding is enabled by default. If disabled, reading a card
+    /// shorter than a full block fails.
+    ///
+    /// # Arguments
+    /// * `pad` - Whether to pad short cards.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut reader =
+    ///     CardReaderDevice::new(vec!["HELLO".to_string()]).with_pad_short_reads(false);
+    /// let mut block = [FullWord::new(); CardReaderDevice::BLOCK_SIZE];
+    ///
+    /// assert!(reader.read(&mut block).is_err());
+    /// assert_eq!(reader.position(), 0);
+    /// ```
+    pub fn with_pad_short_reads(self, pad: bool) -> Self {
+        CardReaderDevice {
+            pad_short_reads: pad,
+            ..self
+        }
+    }
+
+    /// Get the count of cards already read.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Make sure the next card is in the hopper, if there is one.
+    fn helper_pull(&mut self) -> Result<(), ()> {
+        if self.position < self.cards.len() {
+            return Ok(());
+        }
+        self.reader.pull(&mut self.cards)
+    }
+}
+
+impl Default for CardReaderDevice {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl IODevice for CardReaderDevice {
+    fn read(&mut self, buffer: &mut [FullWord]) -> Result<(), ()> {
+        if buffer.len() != Self::BLOCK_SIZE {
+            return Err(());
+        }
+        self.helper_pull()?;
+        let card = self.cards.get(self.position).ok_or(())?;
+        if !self.pad_short_reads && card.chars().count() < Self::BLOCK_SIZE * 5 {
+            return Err(());
+        }
+        encode_line(card, buffer)?;
+        self.position += 1;
+        let _ = self.helper_pull();
+        Ok(())
+    }
+
+    fn write(&mut self, _: &[FullWord]) -> Result<(), usize> {
+        // A card reader never writes.
+        Err(0)
+    }
+
+    fn control(&mut self, _: i16) -> Result<(), ()> {
+        Err(())
+    }
+
+    fn reset(&mut self) {
+        self.position = 0;
+    }
+
+    fn is_busy(&self) -> Result<bool, ()> {
+        Ok(false)
+    }
+
+    fn is_ready(&self) -> Result<bool, ()> {
+        Ok(true)
+    }
+
+    fn at_eof(&self) -> bool {
+        self.position >= self.cards.len() && self.reader.0.is_none()
+    }
+
+    fn get_block_size(&self) -> usize {
+        Self::BLOCK_SIZE
+    }
+}
+
+/// A card punch for [`MixVM`], collecting punched cards in memory.
+///
+/// The card punch is unit `17` in a standard MIX installation.
+/// Each card is a block of [`CardPunchDevice::BLOCK_SIZE`] words.
+/// Cards are kept in memory, unless a writer is set with
+/// [`CardPunchDevice::with_writer()`]. Cards are then written to it
+/// instead, one per line.
+///
+/// [`MixVM`]: crate::MixVM
+#[derive(Debug, Default)]
+pub struct CardPunchDevice {
+    /// The punched cards.
+    cards: Vec<String>,
+
+    /// The writer receiving punched cards instead of `cards`.
+    writer: Backing<dyn Write>,
+}
+
+impl CardPunchDevice {
+    /// Number of words on a card.
+    pub const BLOCK_SIZE: usize = 16;
+
+    /// Create a new card punch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Punch to the given writer instead of keeping cards in memory.
+    ///
+    /// # Arguments
+    /// * `writer` - The writer receiving punched cards.
+    pub fn with_writer(self, writer: Box<dyn Write>) -> Self {
+        CardPunchDevice {
+            writer: Backing(Some(writer)),
+            ..self
+        }
+    }
+
+    /// Get the punched cards.
+    pub fn cards(&self) -> &[String] {
+        &self.cards
+    }
+}
+
+impl IODevice for CardPunchDevice {
+    fn read(&mut self, _: &mut [FullWord]) -> Result<(), ()> {
+        // A card punch never reads.
+        Err(())
+    }
+
+    fn write(&mut self, data: &[FullWord]) -> Result<(), usize> {
+        if data.len() != Self::BLOCK_SIZE {
+            return Err(0);
+        }
+        let card = decode_line(data)?;
+        match self.writer.0.as_mut() {
+            Some(writer) => writeln!(writer, "{card}").map_err(|_| 0),
+            None => {
+                self.cards.push(card);
+                Ok(())
+            }
+        }
+    }
+
+    fn control(&mut self, _: i16) -> Result<(), ()> {
+        Err(())
+    }
+
+    fn flush(&mut self) -> Result<(), ()> {
+        match self.writer.0.as_mut() {
+            Some(writer) => writer.flush().map_err(|_| ()),
+            None => Ok(()),
+        }
+    }
+
+    fn is_busy(&self) -> Result<bool, ()> {
+        Ok(false)
+    }
+
+    fn is_ready(&self) -> Result<bool, ()> {
+        Ok(true)
+    }
+
+    fn get_block_size(&self) -> usize {
+        Self::BLOCK_SIZE
+    }
+}
+
+/// A typewriter terminal for [`MixVM`], reading typed lines and
+/// collecting printed lines in memory.
+///
+/// The typewriter is unit `19` in a standard MIX installation.
+/// Each line is a block of [`TypewriterDevice::BLOCK_SIZE`] words.
+/// Typed lines can also come from a stream set with
+/// [`TypewriterDevice::with_reader()`], and printed lines can go to a
+/// writer set with [`TypewriterDevice::with_writer()`] instead of
+/// being kept in memory.
+///
+/// [`MixVM`]: crate::MixVM
+#[derive(Debug, Default)]
+pub struct TypewriterDevice {
+    /// The lines to be typed in.
+    input: Vec<String>,
+
+    /// The stream supplying lines after those in `input`.
+    reader: Backing<dyn BufRead>,
+
+    /// The index of the next line to be typed in.
+    position: usize,
+
+    /// The printed lines.
+    output: Vec<String>,
+
+    /// The writer receiving printed lines instead of `output`.
+    writer: Backing<dyn Write>,
+}
+
+impl TypewriterDevice {
+    /// Number of words in a line.
+    pub const BLOCK_SIZE: usize = 14;
+
+    /// Create a new typewriter with the given lines to be typed in.
+    ///
+    /// # Arguments
+    /// * `input` - The lines to type in, in order.
+    pub fn new(input: Vec<String>) -> Self {
+        TypewriterDevice {
+            input,
+            ..Default::default()
+        }
+    }
+
+    /// Type in lines from the given stream after those already given.
+    ///
+    /// # Arguments
+    /// * `reader` - The stream supplying lines.
+    pub fn with_reader(self, reader: Box<dyn BufRead>) -> Self {
+        TypewriterDevice {
+            reader: Backing(Some(reader)),
+            ..self
+        }
+    }
+
+    /// Print to the given writer instead of keeping lines in memory.
+    ///
+    /// # Arguments
+    /// * `writer` - The writer receiving printed lines.
+    pub fn with_writer(self, writer: Box<dyn Write>) -> Self {
+        TypewriterDevice {
+            writer: Backing(Some(writer)),
+            ..self
+        }
+    }
+
+    /// Get the printed lines.
+    pub fn output(&self) -> &[String] {
+        &self.output
+    }
+}
+
+impl IODevice for TypewriterDevice {
+    fn read(&mut self, buffer: &mut [FullWord]) -> Result<(), ()> {
+        if buffer.len() != Self::BLOCK_SIZE {
+            return Err(());
+        }
+        if self.position >= self.input.len() {
+            self.reader.pull(&mut self.input)?;
+        }
+        let line = self.input.get(self.position).ok_or(())?;
+        encode_line(line, buffer)?;
+        self.position += 1;
+        Ok(())
+    }
+
+    fn write(&mut self, data: &[FullWord]) -> Result<(), usize> {
+        if data.len() != Self::BLOCK_SIZE {
+            return Err(0);
+        }
+        let line = decode_line(data)?;
+        match self.writer.0.as_mut() {
+            Some(writer) => writeln!(writer, "{line}").map_err(|_| 0),
+            None => {
+                self.output.push(line);
+                Ok(())
+            }
+        }
+    }
+
+    fn control(&mut self, _: i16) -> Result<(), ()> {
+        Err(())
+    }
+
+    fn reset(&mut self) {
+        self.position = 0;
+    }
+
+    fn flush(&mut self) -> Result<(), ()> {
+        match self.writer.0.as_mut() {
+            Some(writer) => writer.flush().map_err(|_| ()),
+            None => Ok(()),
+        }
+    }
+
+    fn is_busy(&self) -> Result<bool, ()> {
+        Ok(false)
+    }
+
+    fn is_ready(&self) -> Result<bool, ()> {
+        Ok(true)
+    }
+
+    fn get_block_size(&self) -> usize {
+        Self::BLOCK_SIZE
+    }
+}
+
+/// A paper tape unit for [`MixVM`], holding its content in memory.
+///
+/// The paper tape is unit `20` in a standard MIX installation. Each
+/// block contains [`PaperTapeDevice::BLOCK_SIZE`] words. Writing a
+/// block at the end of the tape extends it.
+///
+/// # Control commands
+/// * `M == 0` - Rewind the tape.
+///
+/// # Example
+/// ```rust
+/// use mixture::*;
+///
+/// let mut paper_tape = PaperTapeDevice::new();
+/// let block = [FullWord::from_i64(42).0; PaperTapeDevice::BLOCK_SIZE];
+/// let mut buffer = [FullWord::new(); PaperTapeDevice::BLOCK_SIZE];
+///
+/// paper_tape.write(&block).unwrap();
+/// paper_tape.control(0).unwrap();
+/// paper_tape.read(&mut buffer).unwrap();
+/// assert_eq!(buffer, block);
+/// ```
+///
+/// [`MixVM`]: crate::MixVM
+#[derive(Clone, Debug, Default)]
+pub struct PaperTapeDevice {
+    /// The words on the tape.
+    words: Vec<FullWord>,
+
+    /// The index of the block under the head.
+    position: usize,
+}
+
+impl PaperTapeDevice {
+    /// Number of words in a paper tape block.
+    pub const BLOCK_SIZE: usize = 14;
+
+    /// Create a new empty paper tape.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new paper tape holding the given words.
+    ///
+    /// # Arguments
+    /// * `words` - The initial content of the tape.
+    pub fn from_words(words: Vec<FullWord>) -> Self {
+        PaperTapeDevice { words, position: 0 }
+    }
+
+    /// Get the words on the tape.
+    pub fn words(&self) -> &[FullWord] {
+        &self.words
+    }
+
+    /// Get the index of the block under the head.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl IODevice for PaperTapeDevice {
+    fn read(&mut self, buffer: &mut [FullWord]) -> Result<(), ()> {
+        if buffer.len() != Self::BLOCK_SIZE {
+            return Err(());
+        }
+        let start = self.position * Self::BLOCK_SIZE;
+        let block = self.words.get(start..start + Self::BLOCK_SIZE).ok_or(())?;
+        buffer.copy_from_slice(block);
+        self.position += 1;
+        Ok(())
+    }
+
+    fn write(&mut self, data: &[FullWord]) -> Result<(), usize> {
+        if data.len() != Self::BLOCK_SIZE {
+            return Err(0);
+        }
+        let start = self.position * Self::BLOCK_SIZE;
+        let end = start + Self::BLOCK_SIZE;
+        if self.words.len() < end {
+            self.words.resize(end, FullWord::new());
+        }
+        self.words[start..end].copy_from_slice(data);
+        self.position += 1;
+        Ok(())
+    }
+
+    fn control(&mut self, command: i16) -> Result<(), ()> {
+        match command {
+            0 => {
+                self.position = 0;
+                Ok(())
+            }
+            _ => Err(()),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.position = 0;
+    }
+
+    fn is_busy(&self) -> Result<bool, ()> {
+        Ok(false)
+    }
+
+    fn is_ready(&self) -> Result<bool, ()> {
+        Ok(true)
+    }
+
+    fn get_block_size(&self) -> usize {
+        Self::BLOCK_SIZE
+    }
+}
+
+/// Initial content and stream backings of the devices installed by
+/// [`MixVM::install_standard_devices()`].
+///
+/// Streams are optional. Without them, input comes from the given
+/// content and output is kept in memory.
+///
+/// [`MixVM::install_standard_devices()`]: crate::MixVM::install_standard_devices
+pub struct StandardDevicesConfig {
+    /// Content of tape units `0` to `7`.
+    pub tapes: [Vec<FullWord>; 8],
+
+    /// Content of disk units `8` to `15`.
+    pub disks: [Vec<FullWord>; 8],
+
+    /// Count of blocks on each disk unit.
+    pub disk_capacity: usize,
+
+    /// Cards for the card reader, unit `16`.
+    pub cards: Vec<String>,
+
+    /// Stream supplying cards after [`StandardDevicesConfig::cards`],
+    /// one per line.
+    pub cards_reader: Option<Box<dyn BufRead>>,
+
+    /// Stream receiving cards from the card punch, unit `17`.
+    pub punch_writer: Option<Box<dyn Write>>,
+
+    /// Stream receiving lines from the printer, unit `18`.
+    pub printer_writer: Option<Box<dyn Write>>,
+
+    /// Lines to be typed in on the typewriter, unit `19`.
+    pub typewriter: Vec<String>,
+
+    /// Stream supplying lines after
+    /// [`StandardDevicesConfig::typewriter`].
+    pub typewriter_reader: Option<Box<dyn BufRead>>,
+
+    /// Stream receiving lines printed on the typewriter.
+    pub typewriter_writer: Option<Box<dyn Write>>,
+
+    /// Content of the paper tape, unit `20`.
+    pub paper_tape: Vec<FullWord>,
+}
+
+impl Default for StandardDevicesConfig {
+    fn default() -> Self {
+        StandardDevicesConfig {
+            tapes: Default::default(),
+            disks: Default::default(),
+            disk_capacity: DiskDevice::DEFAULT_CAPACITY,
+            cards: Vec::new(),
+            cards_reader: None,
+            punch_writer: None,
+            printer_writer: None,
+            typewriter: Vec::new(),
+            typewriter_reader: None,
+            typewriter_writer: None,
+            paper_tape: Vec::new(),
+        }
+    }
+}
+
+/// Encode a line of text into a block of words in [`Alphabet`],
+/// padding with spaces.
+///
+/// Fails if the line is too long or contains characters not in
+/// [`Alphabet`].
+fn encode_line(line: &str, buffer: &mut [FullWord]) -> Result<(), ()> {
+    let mut chars = line.chars();
+    for word in buffer.iter_mut() {
+        *word = FullWord::new();
+        for byte in &mut word[1..=5] {
+            if let Some(ch) = chars.next() {
+                *byte = Alphabet::try_from(ch)?.try_into()?;
+            }
+        }
+    }
+    match chars.next() {
+        Some(_) => Err(()),
+        None => Ok(()),
+    }
+}
+
+/// Decode a block of words in [`Alphabet`] into a line of text.
+///
+/// Fails with the index of the first word containing bytes not in
+/// [`Alphabet`].
+fn decode_line(data: &[FullWord]) -> Result<String, usize> {
+    let mut line = String::new();
+    for (i, word) in data.iter().enumerate() {
+        for &byte in &word[1..=5] {
+            let ch: char = Alphabet::try_from(byte)
+                .map_err(|_| i)?
+                .try_into()
+                .map_err(|_| i)?;
+            line.push(ch);
+        }
+    }
+    Ok(line)
+}
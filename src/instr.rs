@@ -8,7 +8,7 @@ use super::mem::FullWord;
 /// thus it can be converted from such type after validation.
 ///
 /// [`MixVM`]: crate::MixVM
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 pub struct Instruction {
     /// The signed address, `A`, read big-endian.
     pub addr: i16,
@@ -52,6 +52,77 @@ impl Instruction {
             opcode,
         }
     }
+
+    /// Create a copy of the instruction with another field.
+    ///
+    /// # Arguments
+    /// * `field` - The new field, `F`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let instr = Instruction::new(2000, 0, 0, Opcode::LdA).with_field(13);
+    /// assert_eq!(instr.field, 13);
+    /// assert_eq!(instr.addr, 2000);
+    /// ```
+    pub const fn with_field(self, field: u8) -> Self {
+        Instruction { field, ..self }
+    }
+
+    /// Create a copy of the instruction with another index.
+    ///
+    /// # Arguments
+    /// * `index` - The new index, `I`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let instr = Instruction::new(2000, 5, 0, Opcode::LdA).with_index(1);
+    /// assert_eq!(instr.index, 1);
+    /// assert_eq!(instr.field, 5);
+    /// ```
+    pub const fn with_index(self, index: u8) -> Self {
+        Instruction { index, ..self }
+    }
+
+    /// Split the field into `L` and `R`, where `F = 8 * L + R`.
+    ///
+    /// # Returns
+    /// * `(u8, u8)` - `L` and `R`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let instr = Instruction::new(2000, 13, 0, Opcode::LdA);
+    /// assert_eq!(instr.field_lr(), (1, 5));
+    /// ```
+    pub const fn field_lr(&self) -> (u8, u8) {
+        (self.field / 8, self.field % 8)
+    }
+
+    /// Create a copy of the instruction with the field `(L:R)`.
+    ///
+    /// # Arguments
+    /// * `l` - The left boundary, `L`.
+    /// * `r` - The right boundary, `R`.
+    ///
+    /// # Panics
+    /// * `L` is not less than `32`, or `R` is not less than `8`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let instr = Instruction::new(2000, 0, 0, Opcode::LdA).with_field_lr(1, 3);
+    /// assert_eq!(instr.field, 11);
+    /// ```
+    pub const fn with_field_lr(self, l: u8, r: u8) -> Self {
+        assert!(l < 32 && r < 8, "field (L:R) out of range");
+        self.with_field(8 * l + r)
+    }
 }
 
 impl TryFrom<FullWord> for Instruction {
@@ -100,7 +171,7 @@ impl TryFrom<FullWord> for Instruction {
 /// to multiple operations, using `F` to distinguish among.
 ///
 /// [`MixVM`]: crate::MixVM
-#[derive(Clone, Copy, PartialEq, Eq, Debug, num_enum::TryFromPrimitive)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, num_enum::TryFromPrimitive)]
 #[repr(u8)]
 pub enum Opcode {
     /// * `NOP(0)` - No operation.
@@ -168,19 +239,19 @@ pub enum Opcode {
     /// ```text
     /// rA <- convert(rA)
     /// ```
-    /// 
+    ///
     /// ------
-    /// 
+    ///
     /// * `NOT(9)` (`x-binarith`): Perform bitwise NOT on `rA`, then store result in `rA`.
-    /// 
+    ///
     /// ```text
     /// rA <- !rA
     /// ```
-    /// 
+    ///
     /// * `AND(10)` (`x-binarith`): Perform bitwise AND on `V` and `rA`, then store result in `rA`.
     /// * `OR(11)` (`x-binarith`): Perform bitwise OR on `V` and `rA`, then store result in `rA`.
     /// * `XOR(12)` (`x-binarith`): Perform bitwise XOR on `V` and `rA`, then store result in `rA`.
-    /// 
+    ///
     /// ```text
     /// rA <- rA OP V
     /// ```
@@ -712,6 +783,599 @@ pub enum Opcode {
     CmpX = 63,
 }
 
+impl Opcode {
+    /// Get all opcodes, in ascending order of their values.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// assert_eq!(Opcode::all().len(), 64);
+    /// assert_eq!(Opcode::all()[8], Opcode::LdA);
+    /// ```
+    pub const fn all() -> &'static [Opcode] {
+        &Self::ALL
+    }
+
+    /// All opcodes, in ascending order of their values.
+    const ALL: [Opcode; 64] = [
+        Opcode::Nop,
+        Opcode::Add,
+        Opcode::Sub,
+        Opcode::Mul,
+        Opcode::Div,
+        Opcode::Special,
+        Opcode::Shift,
+        Opcode::Move,
+        Opcode::LdA,
+        Opcode::Ld1,
+        Opcode::Ld2,
+        Opcode::Ld3,
+        Opcode::Ld4,
+        Opcode::Ld5,
+        Opcode::Ld6,
+        Opcode::LdX,
+        Opcode::LdAN,
+        Opcode::Ld1N,
+        Opcode::Ld2N,
+        Opcode::Ld3N,
+        Opcode::Ld4N,
+        Opcode::Ld5N,
+        Opcode::Ld6N,
+        Opcode::LdXN,
+        Opcode::StA,
+        Opcode::St1,
+        Opcode::St2,
+        Opcode::St3,
+        Opcode::St4,
+        Opcode::St5,
+        Opcode::St6,
+        Opcode::StX,
+        Opcode::StJ,
+        Opcode::StZ,
+        Opcode::Jbus,
+        Opcode::Ioc,
+        Opcode::In,
+        Opcode::Out,
+        Opcode::Jred,
+        Opcode::Jmp,
+        Opcode::JA,
+        Opcode::J1,
+        Opcode::J2,
+        Opcode::J3,
+        Opcode::J4,
+        Opcode::J5,
+        Opcode::J6,
+        Opcode::JX,
+        Opcode::ModifyA,
+        Opcode::Modify1,
+        Opcode::Modify2,
+        Opcode::Modify3,
+        Opcode::Modify4,
+        Opcode::Modify5,
+        Opcode::Modify6,
+        Opcode::ModifyX,
+        Opcode::CmpA,
+        Opcode::Cmp1,
+        Opcode::Cmp2,
+        Opcode::Cmp3,
+        Opcode::Cmp4,
+        Opcode::Cmp5,
+        Opcode::Cmp6,
+        Opcode::CmpX,
+    ];
+
+    /// Check if the opcode is a jump, i.e. it may change the flow
+    /// of control.
+    ///
+    /// These are `JMP`, `JA-JX`, `J1-J6`, `JBUS` and `JRED`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// assert!(Opcode::J3.is_jump());
+    /// assert!(Opcode::Jred.is_jump());
+    /// assert!(!Opcode::LdA.is_jump());
+    /// ```
+    pub const fn is_jump(&self) -> bool {
+        matches!(
+            self,
+            Opcode::Jbus
+                | Opcode::Jred
+                | Opcode::Jmp
+                | Opcode::JA
+                | Opcode::J1
+                | Opcode::J2
+                | Opcode::J3
+                | Opcode::J4
+                | Opcode::J5
+                | Opcode::J6
+                | Opcode::JX
+        )
+    }
+
+    /// Check if the opcode works with IO devices.
+    ///
+    /// These are `JBUS`, `IOC`, `IN`, `OUT` and `JRED`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// assert!(Opcode::Out.is_io());
+    /// assert!(Opcode::Jbus.is_io());
+    /// assert!(!Opcode::Jmp.is_io());
+    /// ```
+    pub const fn is_io(&self) -> bool {
+        matches!(
+            self,
+            Opcode::Jbus | Opcode::Ioc | Opcode::In | Opcode::Out | Opcode::Jred
+        )
+    }
+
+    /// Check if the opcode is an arithmetic operation.
+    ///
+    /// These are `ADD`, `SUB`, `MUL` and `DIV`, including their
+    /// variants selected by `F`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// assert!(Opcode::Mul.is_arithmetic());
+    /// assert!(!Opcode::ModifyA.is_arithmetic());
+    /// ```
+    pub const fn is_arithmetic(&self) -> bool {
+        matches!(self, Opcode::Add | Opcode::Sub | Opcode::Mul | Opcode::Div)
+    }
+
+    /// Look up an opcode and its default `F` by a mnemonic.
+    ///
+    /// For mnemonics selecting an operation by `F`, like `ENNA`,
+    /// the field is the one of that operation. For the others, it is
+    /// the default field, like `(0:5)` for `LDA`. Mnemonics of
+    /// extensions are only recognized if the extension is enabled.
+    ///
+    /// # Arguments
+    /// * `mnemonic` - The mnemonic in upper case, e.g. `"LDA"`.
+    ///
+    /// # Returns
+    /// * [`Some((Opcode, u8))`] - The opcode and its default `F`.
+    /// * [`None`] - The mnemonic is unknown.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// assert_eq!(Opcode::from_mnemonic("ENNA"), Some((Opcode::ModifyA, 3)));
+    /// assert_eq!(Opcode::from_mnemonic("SLC"), Some((Opcode::Shift, 4)));
+    /// assert_eq!(Opcode::from_mnemonic("LDA"), Some((Opcode::LdA, 5)));
+    /// assert_eq!(Opcode::from_mnemonic("FOO"), None);
+    /// ```
+    pub fn from_mnemonic(mnemonic: &str) -> Option<(Opcode, u8)> {
+        match mnemonic {
+            "NOP" => Some((Opcode::Nop, 0)),
+            "ADD" => Some((Opcode::Add, 5)),
+            "SUB" => Some((Opcode::Sub, 5)),
+            "MUL" => Some((Opcode::Mul, 5)),
+            "DIV" => Some((Opcode::Div, 5)),
+            "NUM" => Some((Opcode::Special, 0)),
+            "CHAR" => Some((Opcode::Special, 1)),
+            "HLT" => Some((Opcode::Special, 2)),
+            "TRAP" => Some((Opcode::Special, 63)),
+            "SLA" => Some((Opcode::Shift, 0)),
+            "SRA" => Some((Opcode::Shift, 1)),
+            "SLAX" => Some((Opcode::Shift, 2)),
+            "SRAX" => Some((Opcode::Shift, 3)),
+            "SLC" => Some((Opcode::Shift, 4)),
+            "SRC" => Some((Opcode::Shift, 5)),
+            "MOVE" => Some((Opcode::Move, 1)),
+            "LDA" => Some((Opcode::LdA, 5)),
+            "LD1" => Some((Opcode::Ld1, 5)),
+            "LD2" => Some((Opcode::Ld2, 5)),
+            "LD3" => Some((Opcode::Ld3, 5)),
+            "LD4" => Some((Opcode::Ld4, 5)),
+            "LD5" => Some((Opcode::Ld5, 5)),
+            "LD6" => Some((Opcode::Ld6, 5)),
+            "LDX" => Some((Opcode::LdX, 5)),
+            "LDAN" => Some((Opcode::LdAN, 5)),
+            "LD1N" => Some((Opcode::Ld1N, 5)),
+            "LD2N" => Some((Opcode::Ld2N, 5)),
+            "LD3N" => Some((Opcode::Ld3N, 5)),
+            "LD4N" => Some((Opcode::Ld4N, 5)),
+            "LD5N" => Some((Opcode::Ld5N, 5)),
+            "LD6N" => Some((Opcode::Ld6N, 5)),
+            "LDXN" => Some((Opcode::LdXN, 5)),
+            "STA" => Some((Opcode::StA, 5)),
+            "ST1" => Some((Opcode::St1, 5)),
+            "ST2" => Some((Opcode::St2, 5)),
+            "ST3" => Some((Opcode::St3, 5)),
+            "ST4" => Some((Opcode::St4, 5)),
+            "ST5" => Some((Opcode::St5, 5)),
+            "ST6" => Some((Opcode::St6, 5)),
+            "STX" => Some((Opcode::StX, 5)),
+            "STJ" => Some((Opcode::StJ, 2)),
+            "STZ" => Some((Opcode::StZ, 5)),
+            "JBUS" => Some((Opcode::Jbus, 0)),
+            "IOC" => Some((Opcode::Ioc, 0)),
+            "IN" => Some((Opcode::In, 0)),
+            "OUT" => Some((Opcode::Out, 0)),
+            "JRED" => Some((Opcode::Jred, 0)),
+            "JMP" => Some((Opcode::Jmp, 0)),
+            "JSJ" => Some((Opcode::Jmp, 1)),
+            "JOV" => Some((Opcode::Jmp, 2)),
+            "JNOV" => Some((Opcode::Jmp, 3)),
+            "JL" => Some((Opcode::Jmp, 4)),
+            "JE" => Some((Opcode::Jmp, 5)),
+            "JG" => Some((Opcode::Jmp, 6)),
+            "JGE" => Some((Opcode::Jmp, 7)),
+            "JNE" => Some((Opcode::Jmp, 8)),
+            "JLE" => Some((Opcode::Jmp, 9)),
+            "JAN" => Some((Opcode::JA, 0)),
+            "JAZ" => Some((Opcode::JA, 1)),
+            "JAP" => Some((Opcode::JA, 2)),
+            "JANN" => Some((Opcode::JA, 3)),
+            "JANZ" => Some((Opcode::JA, 4)),
+            "JANP" => Some((Opcode::JA, 5)),
+            "J1N" => Some((Opcode::J1, 0)),
+            "J1Z" => Some((Opcode::J1, 1)),
+            "J1P" => Some((Opcode::J1, 2)),
+            "J1NN" => Some((Opcode::J1, 3)),
+            "J1NZ" => Some((Opcode::J1, 4)),
+            "J1NP" => Some((Opcode::J1, 5)),
+            "J2N" => Some((Opcode::J2, 0)),
+            "J2Z" => Some((Opcode::J2, 1)),
+            "J2P" => Some((Opcode::J2, 2)),
+            "J2NN" => Some((Opcode::J2, 3)),
+            "J2NZ" => Some((Opcode::J2, 4)),
+            "J2NP" => Some((Opcode::J2, 5)),
+            "J3N" => Some((Opcode::J3, 0)),
+            "J3Z" => Some((Opcode::J3, 1)),
+            "J3P" => Some((Opcode::J3, 2)),
+            "J3NN" => Some((Opcode::J3, 3)),
+            "J3NZ" => Some((Opcode::J3, 4)),
+            "J3NP" => Some((Opcode::J3, 5)),
+            "J4N" => Some((Opcode::J4, 0)),
+            "J4Z" => Some((Opcode::J4, 1)),
+            "J4P" => Some((Opcode::J4, 2)),
+            "J4NN" => Some((Opcode::J4, 3)),
+            "J4NZ" => Some((Opcode::J4, 4)),
+            "J4NP" => Some((Opcode::J4, 5)),
+            "J5N" => Some((Opcode::J5, 0)),
+            "J5Z" => Some((Opcode::J5, 1)),
+            "J5P" => Some((Opcode::J5, 2)),
+            "J5NN" => Some((Opcode::J5, 3)),
+            "J5NZ" => Some((Opcode::J5, 4)),
+            "J5NP" => Some((Opcode::J5, 5)),
+            "J6N" => Some((Opcode::J6, 0)),
+            "J6Z" => Some((Opcode::J6, 1)),
+            "J6P" => Some((Opcode::J6, 2)),
+            "J6NN" => Some((Opcode::J6, 3)),
+            "J6NZ" => Some((Opcode::J6, 4)),
+            "J6NP" => Some((Opcode::J6, 5)),
+            "JXN" => Some((Opcode::JX, 0)),
+            "JXZ" => Some((Opcode::JX, 1)),
+            "JXP" => Some((Opcode::JX, 2)),
+            "JXNN" => Some((Opcode::JX, 3)),
+            "JXNZ" => Some((Opcode::JX, 4)),
+            "JXNP" => Some((Opcode::JX, 5)),
+            "INCA" => Some((Opcode::ModifyA, 0)),
+            "DECA" => Some((Opcode::ModifyA, 1)),
+            "ENTA" => Some((Opcode::ModifyA, 2)),
+            "ENNA" => Some((Opcode::ModifyA, 3)),
+            "INC1" => Some((Opcode::Modify1, 0)),
+            "DEC1" => Some((Opcode::Modify1, 1)),
+            "ENT1" => Some((Opcode::Modify1, 2)),
+            "ENN1" => Some((Opcode::Modify1, 3)),
+            "INC2" => Some((Opcode::Modify2, 0)),
+            "DEC2" => Some((Opcode::Modify2, 1)),
+            "ENT2" => Some((Opcode::Modify2, 2)),
+            "ENN2" => Some((Opcode::Modify2, 3)),
+            "INC3" => Some((Opcode::Modify3, 0)),
+            "DEC3" => Some((Opcode::Modify3, 1)),
+            "ENT3" => Some((Opcode::Modify3, 2)),
+            "ENN3" => Some((Opcode::Modify3, 3)),
+            "INC4" => Some((Opcode::Modify4, 0)),
+            "DEC4" => Some((Opcode::Modify4, 1)),
+            "ENT4" => Some((Opcode::Modify4, 2)),
+            "ENN4" => Some((Opcode::Modify4, 3)),
+            "INC5" => Some((Opcode::Modify5, 0)),
+            "DEC5" => Some((Opcode::Modify5, 1)),
+            "ENT5" => Some((Opcode::Modify5, 2)),
+            "ENN5" => Some((Opcode::Modify5, 3)),
+            "INC6" => Some((Opcode::Modify6, 0)),
+            "DEC6" => Some((Opcode::Modify6, 1)),
+            "ENT6" => Some((Opcode::Modify6, 2)),
+            "ENN6" => Some((Opcode::Modify6, 3)),
+            "INCX" => Some((Opcode::ModifyX, 0)),
+            "DECX" => Some((Opcode::ModifyX, 1)),
+            "ENTX" => Some((Opcode::ModifyX, 2)),
+            "ENNX" => Some((Opcode::ModifyX, 3)),
+            "CMPA" => Some((Opcode::CmpA, 5)),
+            "CMP1" => Some((Opcode::Cmp1, 5)),
+            "CMP2" => Some((Opcode::Cmp2, 5)),
+            "CMP3" => Some((Opcode::Cmp3, 5)),
+            "CMP4" => Some((Opcode::Cmp4, 5)),
+            "CMP5" => Some((Opcode::Cmp5, 5)),
+            "CMP6" => Some((Opcode::Cmp6, 5)),
+            "CMPX" => Some((Opcode::CmpX, 5)),
+            #[cfg(feature = "x-ieee754")]
+            "F32ADD" => Some((Opcode::Add, 7)),
+            #[cfg(feature = "x-ieee754")]
+            "F32SUB" => Some((Opcode::Sub, 7)),
+            #[cfg(feature = "x-ieee754")]
+            "F32MUL" => Some((Opcode::Mul, 7)),
+            #[cfg(feature = "x-ieee754")]
+            "F32DIV" => Some((Opcode::Div, 7)),
+            #[cfg(feature = "x-ieee754")]
+            "F32CVTF322I4B" => Some((Opcode::Special, 3)),
+            #[cfg(feature = "x-ieee754")]
+            "F32CVTF322I2B" => Some((Opcode::Special, 4)),
+            #[cfg(feature = "x-ieee754")]
+            "F32CVTF322I1B" => Some((Opcode::Special, 5)),
+            #[cfg(feature = "x-ieee754")]
+            "F32CVTI4B2F32" => Some((Opcode::Special, 6)),
+            #[cfg(feature = "x-ieee754")]
+            "F32CVTI2B2F32" => Some((Opcode::Special, 7)),
+            #[cfg(feature = "x-ieee754")]
+            "F32CVTI1B2F32" => Some((Opcode::Special, 8)),
+            #[cfg(feature = "x-ieee754")]
+            "F32JORD" => Some((Opcode::Jmp, 10)),
+            #[cfg(feature = "x-ieee754")]
+            "F32JUNORD" => Some((Opcode::Jmp, 11)),
+            #[cfg(feature = "x-ieee754")]
+            "F32CMPA" => Some((Opcode::CmpA, 7)),
+            #[cfg(feature = "x-ieee754")]
+            "F32CMPX" => Some((Opcode::CmpX, 7)),
+            #[cfg(feature = "x-binarith")]
+            "NOT" => Some((Opcode::Special, 9)),
+            #[cfg(feature = "x-binarith")]
+            "AND" => Some((Opcode::Special, 10)),
+            #[cfg(feature = "x-binarith")]
+            "OR" => Some((Opcode::Special, 11)),
+            #[cfg(feature = "x-binarith")]
+            "XOR" => Some((Opcode::Special, 12)),
+            #[cfg(feature = "x-binary")]
+            "SLB" => Some((Opcode::Shift, 6)),
+            #[cfg(feature = "x-binary")]
+            "SRB" => Some((Opcode::Shift, 7)),
+            #[cfg(feature = "x-binary")]
+            "JAE" => Some((Opcode::JA, 6)),
+            #[cfg(feature = "x-binary")]
+            "JAO" => Some((Opcode::JA, 7)),
+            #[cfg(feature = "x-binary")]
+            "JXE" => Some((Opcode::JX, 6)),
+            #[cfg(feature = "x-binary")]
+            "JXO" => Some((Opcode::JX, 7)),
+            _ => None,
+        }
+    }
+
+    /// Look up the mnemonic of an opcode with a given `F`.
+    ///
+    /// This is the reverse of [`Opcode::from_mnemonic()`]. For
+    /// operations selected by `F`, like `ENNA`, only the fields of
+    /// known operations have a mnemonic. For the others, any field
+    /// gives the same mnemonic, like `LDA` for `LDA 2000(1:3)`.
+    ///
+    /// # Arguments
+    /// * `field` - The field, `F`.
+    ///
+    /// # Returns
+    /// * [`Some(&str)`] - The mnemonic in upper case.
+    /// * [`None`] - No operation is selected by `F`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// assert_eq!(Opcode::ModifyA.mnemonic(3), Some("ENNA"));
+    /// assert_eq!(Opcode::LdA.mnemonic(11), Some("LDA"));
+    /// assert_eq!(Opcode::Jmp.mnemonic(7), Some("JGE"));
+    /// assert_eq!(Opcode::Jmp.mnemonic(42), None);
+    /// ```
+    pub const fn mnemonic(&self, field: u8) -> Option<&'static str> {
+        let mnemonic = match (self, field) {
+            (Opcode::Nop, _) => "NOP",
+            #[cfg(feature = "x-ieee754")]
+            (Opcode::Add, 7) => "F32ADD",
+            (Opcode::Add, _) => "ADD",
+            #[cfg(feature = "x-ieee754")]
+            (Opcode::Sub, 7) => "F32SUB",
+            (Opcode::Sub, _) => "SUB",
+            #[cfg(feature = "x-ieee754")]
+            (Opcode::Mul, 7) => "F32MUL",
+            (Opcode::Mul, _) => "MUL",
+            #[cfg(feature = "x-ieee754")]
+            (Opcode::Div, 7) => "F32DIV",
+            (Opcode::Div, _) => "DIV",
+            (Opcode::Special, 0) => "NUM",
+            (Opcode::Special, 1) => "CHAR",
+            (Opcode::Special, 2) => "HLT",
+            (Opcode::Special, 63) => "TRAP",
+            #[cfg(feature = "x-ieee754")]
+            (Opcode::Special, 3) => "F32CVTF322I4B",
+            #[cfg(feature = "x-ieee754")]
+            (Opcode::Special, 4) => "F32CVTF322I2B",
+            #[cfg(feature = "x-ieee754")]
+            (Opcode::Special, 5) => "F32CVTF322I1B",
+            #[cfg(feature = "x-ieee754")]
+            (Opcode::Special, 6) => "F32CVTI4B2F32",
+            #[cfg(feature = "x-ieee754")]
+            (Opcode::Special, 7) => "F32CVTI2B2F32",
+            #[cfg(feature = "x-ieee754")]
+            (Opcode::Special, 8) => "F32CVTI1B2F32",
+            #[cfg(feature = "x-binarith")]
+            (Opcode::Special, 9) => "NOT",
+            #[cfg(feature = "x-binarith")]
+            (Opcode::Special, 10) => "AND",
+            #[cfg(feature = "x-binarith")]
+            (Opcode::Special, 11) => "OR",
+            #[cfg(feature = "x-binarith")]
+            (Opcode::Special, 12) => "XOR",
+            (Opcode::Shift, 0) => "SLA",
+            (Opcode::Shift, 1) => "SRA",
+            (Opcode::Shift, 2) => "SLAX",
+            (Opcode::Shift, 3) => "SRAX",
+            (Opcode::Shift, 4) => "SLC",
+            (Opcode::Shift, 5) => "SRC",
+            #[cfg(feature = "x-binary")]
+            (Opcode::Shift, 6) => "SLB",
+            #[cfg(feature = "x-binary")]
+            (Opcode::Shift, 7) => "SRB",
+            (Opcode::Move, _) => "MOVE",
+            (Opcode::LdA, _) => "LDA",
+            (Opcode::Ld1, _) => "LD1",
+            (Opcode::Ld2, _) => "LD2",
+            (Opcode::Ld3, _) => "LD3",
+            (Opcode::Ld4, _) => "LD4",
+            (Opcode::Ld5, _) => "LD5",
+            (Opcode::Ld6, _) => "LD6",
+            (Opcode::LdX, _) => "LDX",
+            (Opcode::LdAN, _) => "LDAN",
+            (Opcode::Ld1N, _) => "LD1N",
+            (Opcode::Ld2N, _) => "LD2N",
+            (Opcode::Ld3N, _) => "LD3N",
+            (Opcode::Ld4N, _) => "LD4N",
+            (Opcode::Ld5N, _) => "LD5N",
+            (Opcode::Ld6N, _) => "LD6N",
+            (Opcode::LdXN, _) => "LDXN",
+            (Opcode::StA, _) => "STA",
+            (Opcode::St1, _) => "ST1",
+            (Opcode::St2, _) => "ST2",
+            (Opcode::St3, _) => "ST3",
+            (Opcode::St4, _) => "ST4",
+            (Opcode::St5, _) => "ST5",
+            (Opcode::St6, _) => "ST6",
+            (Opcode::StX, _) => "STX",
+            (Opcode::StJ, _) => "STJ",
+            (Opcode::StZ, _) => "STZ",
+            (Opcode::Jbus, _) => "JBUS",
+            (Opcode::Ioc, _) => "IOC",
+            (Opcode::In, _) => "IN",
+            (Opcode::Out, _) => "OUT",
+            (Opcode::Jred, _) => "JRED",
+            (Opcode::Jmp, 0) => "JMP",
+            (Opcode::Jmp, 1) => "JSJ",
+            (Opcode::Jmp, 2) => "JOV",
+            (Opcode::Jmp, 3) => "JNOV",
+            (Opcode::Jmp, 4) => "JL",
+            (Opcode::Jmp, 5) => "JE",
+            (Opcode::Jmp, 6) => "JG",
+            (Opcode::Jmp, 7) => "JGE",
+            (Opcode::Jmp, 8) => "JNE",
+            (Opcode::Jmp, 9) => "JLE",
+            #[cfg(feature = "x-ieee754")]
+            (Opcode::Jmp, 10) => "F32JORD",
+            #[cfg(feature = "x-ieee754")]
+            (Opcode::Jmp, 11) => "F32JUNORD",
+            (Opcode::JA, 0) => "JAN",
+            (Opcode::JA, 1) => "JAZ",
+            (Opcode::JA, 2) => "JAP",
+            (Opcode::JA, 3) => "JANN",
+            (Opcode::JA, 4) => "JANZ",
+            (Opcode::JA, 5) => "JANP",
+            #[cfg(feature = "x-binary")]
+            (Opcode::JA, 6) => "JAE",
+            #[cfg(feature = "x-binary")]
+            (Opcode::JA, 7) => "JAO",
+            (Opcode::J1, 0) => "J1N",
+            (Opcode::J1, 1) => "J1Z",
+            (Opcode::J1, 2) => "J1P",
+            (Opcode::J1, 3) => "J1NN",
+            (Opcode::J1, 4) => "J1NZ",
+            (Opcode::J1, 5) => "J1NP",
+            (Opcode::J2, 0) => "J2N",
+            (Opcode::J2, 1) => "J2Z",
+            (Opcode::J2, 2) => "J2P",
+            (Opcode::J2, 3) => "J2NN",
+            (Opcode::J2, 4) => "J2NZ",
+            (Opcode::J2, 5) => "J2NP",
+            (Opcode::J3, 0) => "J3N",
+            (Opcode::J3, 1) => "J3Z",
+            (Opcode::J3, 2) => "J3P",
+            (Opcode::J3, 3) => "J3NN",
+            (Opcode::J3, 4) => "J3NZ",
+            (Opcode::J3, 5) => "J3NP",
+            (Opcode::J4, 0) => "J4N",
+            (Opcode::J4, 1) => "J4Z",
+            (Opcode::J4, 2) => "J4P",
+            (Opcode::J4, 3) => "J4NN",
+            (Opcode::J4, 4) => "J4NZ",
+            (Opcode::J4, 5) => "J4NP",
+            (Opcode::J5, 0) => "J5N",
+            (Opcode::J5, 1) => "J5Z",
+            (Opcode::J5, 2) => "J5P",
+            (Opcode::J5, 3) => "J5NN",
+            (Opcode::J5, 4) => "J5NZ",
+            (Opcode::J5, 5) => "J5NP",
+            (Opcode::J6, 0) => "J6N",
+            (Opcode::J6, 1) => "J6Z",
+            (Opcode::J6, 2) => "J6P",
+            (Opcode::J6, 3) => "J6NN",
+            (Opcode::J6, 4) => "J6NZ",
+            (Opcode::J6, 5) => "J6NP",
+            (Opcode::JX, 0) => "JXN",
+            (Opcode::JX, 1) => "JXZ",
+            (Opcode::JX, 2) => "JXP",
+            (Opcode::JX, 3) => "JXNN",
+            (Opcode::JX, 4) => "JXNZ",
+            (Opcode::JX, 5) => "JXNP",
+            #[cfg(feature = "x-binary")]
+            (Opcode::JX, 6) => "JXE",
+            #[cfg(feature = "x-binary")]
+            (Opcode::JX, 7) => "JXO",
+            (Opcode::ModifyA, 0) => "INCA",
+            (Opcode::ModifyA, 1) => "DECA",
+            (Opcode::ModifyA, 2) => "ENTA",
+            (Opcode::ModifyA, 3) => "ENNA",
+            (Opcode::Modify1, 0) => "INC1",
+            (Opcode::Modify1, 1) => "DEC1",
+            (Opcode::Modify1, 2) => "ENT1",
+            (Opcode::Modify1, 3) => "ENN1",
+            (Opcode::Modify2, 0) => "INC2",
+            (Opcode::Modify2, 1) => "DEC2",
+            (Opcode::Modify2, 2) => "ENT2",
+            (Opcode::Modify2, 3) => "ENN2",
+            (Opcode::Modify3, 0) => "INC3",
+            (Opcode::Modify3, 1) => "DEC3",
+            (Opcode::Modify3, 2) => "ENT3",
+            (Opcode::Modify3, 3) => "ENN3",
+            (Opcode::Modify4, 0) => "INC4",
+            (Opcode::Modify4, 1) => "DEC4",
+            (Opcode::Modify4, 2) => "ENT4",
+            (Opcode::Modify4, 3) => "ENN4",
+            (Opcode::Modify5, 0) => "INC5",
+            (Opcode::Modify5, 1) => "DEC5",
+            (Opcode::Modify5, 2) => "ENT5",
+            (Opcode::Modify5, 3) => "ENN5",
+            (Opcode::Modify6, 0) => "INC6",
+            (Opcode::Modify6, 1) => "DEC6",
+            (Opcode::Modify6, 2) => "ENT6",
+            (Opcode::Modify6, 3) => "ENN6",
+            (Opcode::ModifyX, 0) => "INCX",
+            (Opcode::ModifyX, 1) => "DECX",
+            (Opcode::ModifyX, 2) => "ENTX",
+            (Opcode::ModifyX, 3) => "ENNX",
+            #[cfg(feature = "x-ieee754")]
+            (Opcode::CmpA, 7) => "F32CMPA",
+            (Opcode::CmpA, _) => "CMPA",
+            (Opcode::Cmp1, _) => "CMP1",
+            (Opcode::Cmp2, _) => "CMP2",
+            (Opcode::Cmp3, _) => "CMP3",
+            (Opcode::Cmp4, _) => "CMP4",
+            (Opcode::Cmp5, _) => "CMP5",
+            (Opcode::Cmp6, _) => "CMP6",
+            #[cfg(feature = "x-ieee754")]
+            (Opcode::CmpX, 7) => "F32CMPX",
+            (Opcode::CmpX, _) => "CMPX",
+            _ => return None,
+        };
+        Some(mnemonic)
+    }
+}
+
 /// Used when converting a type to a [`RangeInclusive<T>`].
 pub trait ToRangeInclusive<T> {
     /// Convert some value to [`RangeInclusive<T>`].
@@ -1,3 +1,5 @@
+use core::any::Any;
+
 use crate::FullWord;
 
 /// A device plugged into a [`MixVM`] to perform IO
@@ -5,7 +7,8 @@ use crate::FullWord;
 ///
 /// This trait is used to build IO operations that may have side
 /// effects. Implement the trait and insert the device to a [`MixVM`]
-/// instance to apply it.
+/// instance to apply it. Installed devices can be inspected later
+/// with [`MixVM::device_ref()`].
 ///
 /// # Example
 /// ```rust
@@ -48,7 +51,8 @@ use crate::FullWord;
 /// ```
 ///
 /// [`MixVM`]: crate::MixVM
-pub trait IODevice {
+/// [`MixVM::device_ref()`]: crate::MixVM::device_ref
+pub trait IODevice: Any {
     /// Read a block of [`FullWord`]s from the device into the buffer.
     ///
     /// The amount of words in a block is defined by the device
@@ -76,10 +80,37 @@ pub trait IODevice {
 
     /// Issue a control command to the device.
     ///
+    /// The command is `M` of an `IOC M(F)` instruction. Its meaning
+    /// depends on the class of the device. Canonical commands are:
+    /// * Tape units - `M == 0` rewinds the tape; `M < 0` skips
+    ///   backward `-M` blocks; `M > 0` skips forward `M` blocks.
+    /// * Line printers - `M == 0` skips to the top of the next page.
+    /// * Paper tapes - `M == 0` rewinds the tape.
+    /// * Disks - `M == 0` only moves to the block given by
+    ///   [`IODevice::seek()`].
+    ///
+    /// Devices should fail on commands they do not support.
+    ///
     /// # Arguments
     /// * `command` - The command to issue.
     fn control(&mut self, command: i16) -> Result<(), ()>;
 
+    /// Move to the block given by `rX`.
+    ///
+    /// This is called by [`MixVM`] before `IN`, `OUT` and `IOC` on
+    /// units `8` to `15`, which are disks taking their block address
+    /// from `rX`. Devices without such addressing need not override
+    /// it.
+    ///
+    /// # Arguments
+    /// * `position` - The value of `rX`.
+    ///
+    /// [`MixVM`]: crate::MixVM
+    fn seek(&mut self, position: i64) -> Result<(), ()> {
+        let _ = position;
+        Ok(())
+    }
+
     /// Check if the device is busy.
     ///
     /// Note that when a device detects any malfunctions, like
@@ -89,7 +120,38 @@ pub trait IODevice {
     /// Check if the device is ready for next operations.
     fn is_ready(&self) -> Result<bool, ()>;
 
+    /// Reset the device to its initial position.
+    ///
+    /// This is called for every installed device by
+    /// [`MixVM::reset()`]. Devices with a medium, like tapes, should
+    /// rewind it. Content already on the medium is kept. Devices
+    /// without such state need not override it.
+    ///
+    /// [`MixVM::reset()`]: crate::MixVM::reset
+    fn reset(&mut self) {}
+
+    /// Check if the device has no more input to read.
+    ///
+    /// A device at end of input is never reported ready by `JRED`,
+    /// so programs can detect exhaustion without a sentinel.
+    /// Devices without a notion of end of input need not override it.
+    fn at_eof(&self) -> bool {
+        false
+    }
+
     /// Get the count of [`FullWord`]s in a device block,
     /// that is, read or written in a single operation.
     fn get_block_size(&self) -> usize;
+
+    /// Flush any buffered output of the device.
+    ///
+    /// Devices may hold written blocks, e.g. a partial page of a
+    /// printer, until flushed. This is called by
+    /// [`MixVM::flush_io()`], including when the machine executes
+    /// `HLT`. Unbuffered devices need not override it.
+    ///
+    /// [`MixVM::flush_io()`]: crate::MixVM::flush_io
+    fn flush(&mut self) -> Result<(), ()> {
+        Ok(())
+    }
 }
@@ -27,6 +27,9 @@ cfg_block! {
     #[cfg(feature = "io")] {
         mod io;
         pub use io::*;
+
+        mod devices;
+        pub use devices::*;
     }
 }
 
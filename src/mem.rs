@@ -1,10 +1,42 @@
+use core::fmt;
+use core::ops::Add;
 use core::ops::Index;
 use core::ops::IndexMut;
+use core::ops::Mul;
+use core::ops::Neg;
 use core::ops::Range;
 use core::ops::RangeFull;
 use core::ops::RangeInclusive;
+use core::ops::Sub;
+#[cfg(feature = "std")]
+use std::format;
+#[cfg(feature = "std")]
+use std::string::String;
 
+use super::alphabet::Alphabet;
 use super::instr::Instruction;
+use super::instr::Opcode;
+use super::instr::ToRangeInclusive;
+
+/// The sign of a [`Word`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Sign {
+    /// The sign `+`.
+    Positive,
+
+    /// The sign `-`.
+    Negative,
+}
+
+/// The error of converting a value too large for a [`Word`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OverflowError {
+    /// The value being converted.
+    pub value: i64,
+
+    /// The largest magnitude the word can hold.
+    pub max_magnitude: u64,
+}
 
 /// A generic word in [`MixVM`] with `N` bytes in it.
 ///
@@ -19,6 +51,15 @@ use super::instr::Instruction;
 /// A byte should be able to represent a scalar no less than
 /// decimal `60`.
 ///
+/// Words support `+`, `-`, `*` and negation for host-side arithmetic. These
+/// follow MIX sign-magnitude semantics rather than two's complement:
+/// the sign byte holds the sign and the other bytes hold the
+/// magnitude. Overflows panic in debug builds; use
+/// [`Word::checked_add()`] and friends to handle them.
+///
+/// Equality and hashing compare the raw bytes, so `+0` and `-0`
+/// are different words.
+///
 /// # Generic Parameters
 /// * `N` - The number of bytes in the word, including sign.
 /// * `P` - Whether the sign byte is always positive.
@@ -40,7 +81,7 @@ use super::instr::Instruction;
 /// ```
 ///
 /// [`MixVM`]: crate::MixVM
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 pub struct Word<const N: usize, const P: bool> {
     data: [u8; N],
 }
@@ -107,10 +148,8 @@ impl<const N: usize, const P: bool> Word<N, P> {
     /// ```
     pub fn from_i64(value: i64) -> (Self, bool) {
         let mut word = Self::new();
-        let bytes = value.abs().to_be_bytes();
-        // See if we have something not copied.
-        // Bytes marked 'dirty' have not been copied yet.
-        let overflow = N - 1 < 8 && bytes[0..8 - (N - 1)].iter().any(|&b| b != 0);
+        let bytes = value.unsigned_abs().to_be_bytes();
+        let overflow = value.unsigned_abs() as u128 > Self::max_magnitude(256);
         word[0] = if !P && value < 0 {
             Self::NEG
         } else {
@@ -119,10 +158,115 @@ impl<const N: usize, const P: bool> Word<N, P> {
         for (word_i, bytes_i) in (1..N).rev().zip((0..8).rev()) {
             word[word_i] = bytes[bytes_i];
         }
-        // If we have left some data behind, we have overflowed.
         (word, overflow)
     }
 
+    /// Create a new word from an `i64`, failing if it is too large.
+    ///
+    /// # Arguments
+    /// * `value` - The value to initialize the word with.
+    ///
+    /// # Returns
+    /// * [`Ok(Word)`] - The initialized word.
+    /// * [`Err(OverflowError)`] - The magnitude of `value` exceeds
+    ///   the largest one the word can hold.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let word = HalfWord::from_i64_checked(-0xFFFF).unwrap();
+    /// assert_eq!(word[..], [1, 0xFF, 0xFF]);
+    ///
+    /// let err = HalfWord::from_i64_checked(0x10000).unwrap_err();
+    /// assert_eq!(err.max_magnitude, 0xFFFF);
+    /// ```
+    pub fn from_i64_checked(value: i64) -> Result<Self, OverflowError> {
+        match Self::from_i64(value) {
+            (word, false) => Ok(word),
+            (_, true) => Err(OverflowError {
+                value,
+                max_magnitude: Self::max_magnitude(256).min(u64::MAX as u128) as u64,
+            }),
+        }
+    }
+
+    /// Get the number of bits in the magnitude of the word.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// assert_eq!(FullWord::bit_width(), 40);
+    /// assert_eq!(HalfWord::bit_width(), 16);
+    /// ```
+    pub const fn bit_width() -> u32 {
+        8 * (N as u32 - 1)
+    }
+
+    /// Get the largest magnitude the word can hold, with bytes of
+    /// the given size.
+    ///
+    /// Conversions and saturating arithmetic use this with a radix of
+    /// `256` to find the capacity of a word.
+    ///
+    /// # Arguments
+    /// * `radix` - The number of values a byte can hold, e.g. `256`
+    ///   for this crate, or `100` for a decimal MIX.
+    ///
+    /// # Returns
+    /// * [`u128`] - The largest magnitude, saturated to [`u128::MAX`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// assert_eq!(FullWord::max_magnitude(256), 0xFF_FFFF_FFFF);
+    /// assert_eq!(FullWord::max_magnitude(100), 99_99_99_99_99);
+    /// ```
+    pub const fn max_magnitude(radix: u64) -> u128 {
+        let mut count: u128 = 1;
+        let mut i = 1;
+        while i < N {
+            count = match count.checked_mul(radix as u128) {
+                Some(count) => count,
+                None => return u128::MAX,
+            };
+            i += 1;
+        }
+        count.saturating_sub(1)
+    }
+
+    /// Get the smallest value the word can hold, with bytes of the
+    /// given size.
+    ///
+    /// This is `0` for words whose sign is always positive.
+    ///
+    /// # Arguments
+    /// * `radix` - The number of values a byte can hold.
+    ///
+    /// # Returns
+    /// * [`i128`] - The smallest value, saturated to `-i128::MAX`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// assert_eq!(HalfWord::min_value(64), -4095);
+    /// assert_eq!(PosHalfWord::min_value(64), 0);
+    /// ```
+    pub const fn min_value(radix: u64) -> i128 {
+        if P {
+            return 0;
+        }
+        let max = Self::max_magnitude(radix);
+        if max > i128::MAX as u128 {
+            -i128::MAX
+        } else {
+            -(max as i128)
+        }
+    }
+
     /// Set the content of the whole word to given array.
     ///
     /// # Arguments
@@ -187,6 +331,55 @@ impl<const N: usize, const P: bool> Word<N, P> {
         }
     }
 
+    /// Get the sign of the word.
+    ///
+    /// # Returns
+    /// * [`Sign::Positive`] - If the word is positive.
+    /// * [`Sign::Negative`] - If the word is negative.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let word = Word::<6, false>::from_bytes([Word::<6, false>::NEG, 1, 2, 3, 4, 5]);
+    /// assert_eq!(word.sign(), Sign::Negative);
+    /// ```
+    pub const fn sign(&self) -> Sign {
+        if self.is_positive() {
+            Sign::Positive
+        } else {
+            Sign::Negative
+        }
+    }
+
+    /// Set the sign of the word.
+    ///
+    /// This method always sets [`Sign::Positive`] if the word
+    /// is always positive, i.e. `P == true`.
+    ///
+    /// # Arguments
+    /// * `sign` - The new sign.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut word = Word::<6, false>::new();
+    /// word.set_sign(Sign::Negative);
+    /// assert_eq!(word[0], Word::<6, false>::NEG);
+    ///
+    /// let mut word_pos = Word::<3, true>::new();
+    /// word_pos.set_sign(Sign::Negative);
+    /// assert_eq!(word_pos[0], Word::<3, true>::POS);
+    /// ```
+    pub fn set_sign(&mut self, sign: Sign) {
+        self.data[0] = if !P && sign == Sign::Negative {
+            Self::NEG
+        } else {
+            Self::POS
+        };
+    }
+
     /// Flip the sign of the word.
     ///
     /// This method has no effect if the word is always positive,
@@ -212,6 +405,160 @@ impl<const N: usize, const P: bool> Word<N, P> {
         };
     }
 
+    /// Rotate the bytes of the magnitude cyclically, as `SLC` and
+    /// `SRC` do. The sign is kept.
+    ///
+    /// # Arguments
+    /// * `by` - The count of bytes to rotate by. May exceed the
+    ///   length of the magnitude.
+    /// * `left` - `true` to rotate left, `false` to rotate right.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut word = FullWord::from_bytes([1, 1, 2, 3, 4, 5]);
+    /// word.rotate_magnitude(2, true);
+    /// assert_eq!(word[..], [1, 3, 4, 5, 1, 2]);
+    /// word.rotate_magnitude(7, false);
+    /// assert_eq!(word[..], [1, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn rotate_magnitude(&mut self, by: usize, left: bool) {
+        let magnitude = &mut self.data[1..];
+        if magnitude.is_empty() {
+            return;
+        }
+        let by = by % magnitude.len();
+        if left {
+            magnitude.rotate_left(by);
+        } else {
+            magnitude.rotate_right(by);
+        }
+    }
+
+    /// Extract a field of the word into a new [`FullWord`].
+    ///
+    /// The bytes in `(L:R)` are copied right-justified, with
+    /// all other bytes zeroed, just as `LDA` does. The sign is copied
+    /// if `L == 0`, otherwise it is positive.
+    ///
+    /// # Arguments
+    /// * `field` - The field `F = 8 * L + R` to extract.
+    ///
+    /// # Returns
+    /// * [`FullWord`] - The extracted word.
+    ///
+    /// # Panics
+    /// * `R` is out of the range of the word.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let word = FullWord::from_bytes([1, 1, 2, 3, 4, 5]);
+    /// assert_eq!(word.extract_field(11)[..], [0, 0, 0, 1, 2, 3]);
+    /// assert_eq!(word.extract_field(2)[..], [1, 0, 0, 0, 1, 2]);
+    /// ```
+    pub fn extract_field(&self, field: u8) -> FullWord {
+        let (field, sign_copy_needed) = field.to_range_inclusive_signless();
+        // Handle 'understood' positive sign.
+        let mut result = FullWord::from_bytes([FullWord::POS, 0, 0, 0, 0, 0]);
+        // Copy bytes shifted right.
+        for (result_cursor, self_cursor) in (1..=5).rev().zip(field.rev()) {
+            result[result_cursor] = self[self_cursor];
+        }
+        // Copy sign byte if needed.
+        if sign_copy_needed {
+            result[0] = self[0];
+        }
+        result
+    }
+
+    /// Write a word into a field of this word.
+    ///
+    /// The bytes of `value` are copied right-justified into `(L:R)`,
+    /// just as `STA` does. Bytes of the field beyond those of `value`
+    /// are zeroed. The sign is copied if `L == 0`, unless the sign of
+    /// this word is always positive.
+    ///
+    /// # Arguments
+    /// * `field` - The field `F = 8 * L + R` to write.
+    /// * `value` - The word to write.
+    ///
+    /// # Panics
+    /// * `R` is out of the range of the word.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut word = FullWord::from_bytes([0, 1, 2, 3, 4, 5]);
+    /// word.set_field(19, &FullWord::from_bytes([1, 6, 7, 8, 9, 10]));
+    /// assert_eq!(word[..], [0, 1, 9, 10, 4, 5]);
+    ///
+    /// word.set_field(5, &HalfWord::from_bytes([1, 6, 7]));
+    /// assert_eq!(word[..], [1, 0, 0, 0, 6, 7]);
+    /// ```
+    pub fn set_field<const M: usize, const Q: bool>(&mut self, field: u8, value: &Word<M, Q>) {
+        let (field, sign_copy_needed) = field.to_range_inclusive_signless();
+        // Copy bytes shifted right.
+        let mut value_bytes = value.data[1..].iter().rev();
+        for self_cursor in field.rev() {
+            self.data[self_cursor] = value_bytes.next().copied().unwrap_or(0);
+        }
+        // Copy sign byte if needed.
+        if sign_copy_needed && !P {
+            self.data[0] = value.data[0];
+        }
+    }
+
+    /// Normalize the word as a MIX floating-point number.
+    ///
+    /// A MIX floating-point number has its exponent in byte 1 and
+    /// its fraction in bytes 2 to `N - 1`. See D. E. Knuth, *The Art
+    /// of Computer Programming*, Volume 2, pp 214 for more information.
+    ///
+    /// The fraction is shifted left until its most significant byte
+    /// is non-zero, and the exponent is decreased by the number of
+    /// bytes shifted. A zero fraction yields a zero exponent. The sign
+    /// is kept.
+    ///
+    /// No instruction uses this yet, since MIX floating-point
+    /// instructions are not implemented. It is only available with
+    /// the float extension.
+    ///
+    /// # Returns
+    /// * [`Word`] - The normalized word. The exponent wraps around on underflow.
+    /// * [`bool`] - `true` if the exponent underflows, `false` otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let word = Word::<6, false>::from_bytes([0, 130, 0, 0, 1, 2]);
+    /// let (normalized, underflow) = word.fp_normalize();
+    /// assert_eq!(underflow, false);
+    /// assert_eq!(normalized[..], [0, 128, 1, 2, 0, 0]);
+    /// ```
+    #[cfg(feature = "x-ieee754")]
+    pub fn fp_normalize(&self) -> (Self, bool) {
+        let mut word = *self;
+        let fraction = &self.data[2..N];
+        let shift = match fraction.iter().position(|&b| b != 0) {
+            Some(shift) => shift,
+            None => {
+                // Zero fraction, thus zero exponent.
+                word.data[1..N].fill(0);
+                return (word, false);
+            }
+        };
+        word.data[2..N].fill(0);
+        word.data[2..N - shift].copy_from_slice(&fraction[shift..]);
+        let (exponent, underflow) = self.data[1].overflowing_sub(shift as u8);
+        word.data[1] = exponent;
+        (word, underflow)
+    }
+
     /// Convert the word to an `i64`.
     ///
     /// This method squashes big-endian representation of the bytes
@@ -302,6 +649,275 @@ impl<const N: usize, const P: bool> Word<N, P> {
 
         (value * sign, data_bytes_nonzero_count > 8)
     }
+
+    /// Add two words, checking for overflow.
+    ///
+    /// # Arguments
+    /// * `rhs` - The word to add.
+    ///
+    /// # Returns
+    /// * [`Some(Word)`] - The sum.
+    /// * [`None`] - The sum does not fit in the word.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let a = FullWord::from_i64(100).0;
+    /// let b = FullWord::from_i64(-200).0;
+    /// assert_eq!(a.checked_add(b), Some(FullWord::from_i64(-100).0));
+    ///
+    /// let max = FullWord::from_i64(0xFFFFFFFFFF).0;
+    /// assert_eq!(max.checked_add(a), None);
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_op(rhs, |a, b| a + b) {
+            (word, false) => Some(word),
+            (_, true) => None,
+        }
+    }
+
+    /// Subtract a word from another, checking for overflow.
+    ///
+    /// # Arguments
+    /// * `rhs` - The word to subtract.
+    ///
+    /// # Returns
+    /// * [`Some(Word)`] - The difference.
+    /// * [`None`] - The difference does not fit in the word.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let a = PosHalfWord::from_i64(100).0;
+    /// let b = PosHalfWord::from_i64(200).0;
+    /// assert_eq!(b.checked_sub(a), Some(a));
+    /// assert_eq!(a.checked_sub(b), None);
+    /// ```
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_op(rhs, |a, b| a - b) {
+            (word, false) => Some(word),
+            (_, true) => None,
+        }
+    }
+
+    /// Multiply two words, checking for overflow.
+    ///
+    /// # Arguments
+    /// * `rhs` - The word to multiply by.
+    ///
+    /// # Returns
+    /// * [`Some(Word)`] - The product.
+    /// * [`None`] - The product does not fit in the word.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let a = HalfWord::from_i64(-100).0;
+    /// let b = HalfWord::from_i64(300).0;
+    /// assert_eq!(a.checked_mul(b), Some(HalfWord::from_i64(-30000).0));
+    /// assert_eq!(b.checked_mul(b), None);
+    /// ```
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_op(rhs, |a, b| a * b) {
+            (word, false) => Some(word),
+            (_, true) => None,
+        }
+    }
+
+    /// Format the word as a MIX number with the given byte size.
+    ///
+    /// Each byte is printed in decimal, zero-padded to the width of
+    /// `radix - 1`, with no separators between bytes. With `radix`
+    /// being 100, this gives the decimal digits of a decimal MIX
+    /// word, two per byte, as printed by Knuth.
+    ///
+    /// # Arguments
+    /// * `radix` - The byte size of the machine, at least 2.
+    ///
+    /// # Returns
+    /// * [`Ok(String)`] - The sign followed by the digits of the word.
+    /// * [`Err(())`] - `radix` is less than 2, or a byte does not fit in it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let word = FullWord::from_bytes([1, 0, 0, 0, 12, 34]);
+    /// assert_eq!(word.to_mix_number_string(100), Ok("-0000001234".to_string()));
+    /// assert_eq!(word.to_mix_number_string(10), Err(()));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_mix_number_string(&self, radix: u8) -> Result<String, ()> {
+        if radix < 2 || self.data[1..].iter().any(|&b| b >= radix) {
+            return Err(());
+        }
+        let width = (radix - 1).ilog10() as usize + 1;
+        let mut s = String::with_capacity(1 + (N - 1) * width);
+        s.push(if self.is_positive() { '+' } else { '-' });
+        for byte in &self.data[1..] {
+            s.push_str(&format!("{:0width$}", byte, width = width));
+        }
+        Ok(s)
+    }
+
+    /// Create a new word from its decimal card form.
+    ///
+    /// The card form holds two decimal digits per byte, optionally
+    /// preceded by a sign, e.g. `-0000001234` for a [`FullWord`]. This
+    /// is how classic MIX assemblers punch words on cards.
+    ///
+    /// # Arguments
+    /// * `s` - The card form of the word.
+    ///
+    /// # Returns
+    /// * [`Ok(Word)`] - The parsed word.
+    /// * [`Err(())`] - `s` does not hold exactly two digits per byte.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let word = FullWord::from_card_field("-0000001234").unwrap();
+    /// assert_eq!(word[..], [1, 0, 0, 0, 12, 34]);
+    /// assert!(FullWord::from_card_field("1234").is_err());
+    /// ```
+    pub fn from_card_field(s: &str) -> Result<Self, ()> {
+        let (sign, digits) = match s.as_bytes().first() {
+            Some(b'+') => (Self::POS, &s.as_bytes()[1..]),
+            Some(b'-') => (Self::NEG, &s.as_bytes()[1..]),
+            _ => (Self::POS, s.as_bytes()),
+        };
+        if digits.len() != 2 * (N - 1) || !digits.iter().all(u8::is_ascii_digit) {
+            return Err(());
+        }
+        let mut bytes = [0; N];
+        bytes[0] = sign;
+        for (byte, pair) in bytes[1..].iter_mut().zip(digits.chunks_exact(2)) {
+            *byte = (pair[0] - b'0') * 10 + (pair[1] - b'0');
+        }
+        Ok(Self::from_bytes(bytes))
+    }
+
+    /// Format the word in its decimal card form.
+    ///
+    /// This is the inverse of [`Word::from_card_field()`], and the
+    /// same as [`Word::to_mix_number_string()`] with radix `100`.
+    ///
+    /// # Returns
+    /// * [`Ok(String)`] - The card form of the word.
+    /// * [`Err(())`] - A byte does not fit in two decimal digits.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let word = FullWord::from_bytes([1, 0, 0, 0, 12, 34]);
+    /// assert_eq!(word.to_card_field(), Ok("-0000001234".to_string()));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_card_field(&self) -> Result<String, ()> {
+        self.to_mix_number_string(100)
+    }
+
+    /// Format the word with its bytes in octal, e.g.
+    /// `- 000 000 000 014 042`.
+    ///
+    /// This is the inverse of [`Word::from_octal_string()`].
+    ///
+    /// # Returns
+    /// * [`String`] - The octal form of the word.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let word = FullWord::from_bytes([1, 0, 0, 0, 12, 255]);
+    /// assert_eq!(word.to_octal_string(), "- 000 000 000 014 377");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_octal_string(&self) -> String {
+        let mut s = String::with_capacity(1 + (N - 1) * 4);
+        s.push(if self.is_positive() { '+' } else { '-' });
+        for byte in &self.data[1..] {
+            s.push_str(&format!(" {:03o}", byte));
+        }
+        s
+    }
+
+    /// Create a new word from its bytes in octal.
+    ///
+    /// The sign is optional, and the bytes are separated by
+    /// whitespace, e.g. `- 000 000 000 014 042` for a [`FullWord`].
+    ///
+    /// # Arguments
+    /// * `s` - The octal form of the word.
+    ///
+    /// # Returns
+    /// * [`Ok(Word)`] - The parsed word.
+    /// * [`Err(())`] - `s` does not hold exactly one octal byte value
+    ///   per byte.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let word = FullWord::from_octal_string("- 0 0 0 14 377").unwrap();
+    /// assert_eq!(word[..], [1, 0, 0, 0, 12, 255]);
+    /// assert!(FullWord::from_octal_string("+ 0 0 0 0 400").is_err());
+    /// assert!(FullWord::from_octal_string("+ 0 0 0 8").is_err());
+    /// ```
+    pub fn from_octal_string(s: &str) -> Result<Self, ()> {
+        let (sign, rest) = if let Some(rest) = s.strip_prefix('+') {
+            (Self::POS, rest)
+        } else if let Some(rest) = s.strip_prefix('-') {
+            (Self::NEG, rest)
+        } else {
+            (Self::POS, s)
+        };
+        let mut bytes = [0; N];
+        bytes[0] = sign;
+        let mut values = rest.split_whitespace();
+        for byte in &mut bytes[1..] {
+            let value = values.next().ok_or(())?;
+            if !value.bytes().all(|b| (b'0'..=b'7').contains(&b)) {
+                return Err(());
+            }
+            *byte = u8::from_str_radix(value, 8).map_err(|_| ())?;
+        }
+        if values.next().is_some() {
+            return Err(());
+        }
+        Ok(Self::from_bytes(bytes))
+    }
+
+    /// Apply an arithmetic operation on the values of two words.
+    ///
+    /// The magnitude of the result is truncated to fit in the
+    /// word. A negative result overflows if `P` is `true`.
+    ///
+    /// # Returns
+    /// * [`Word`] - The truncated result.
+    /// * [`bool`] - `true` if the result does not fit, `false` otherwise.
+    fn overflowing_op(self, rhs: Self, op: fn(i128, i128) -> i128) -> (Self, bool) {
+        let value = op(self.to_i64().0 as i128, rhs.to_i64().0 as i128);
+        let bytes = value.unsigned_abs().to_be_bytes();
+        let mut word = Self::new();
+        // Bytes not fitting in the word are lost.
+        let overflow =
+            (N - 1 < 16 && bytes[0..16 - (N - 1)].iter().any(|&b| b != 0)) || (P && value < 0);
+        word[0] = if !P && value < 0 {
+            Self::NEG
+        } else {
+            Self::POS
+        };
+        for (word_i, bytes_i) in (1..N).rev().zip((0..16).rev()) {
+            word[word_i] = bytes[bytes_i];
+        }
+        (word, overflow)
+    }
 }
 
 impl<const N: usize, const P: bool> Default for Word<N, P> {
@@ -313,6 +929,51 @@ impl<const N: usize, const P: bool> Default for Word<N, P> {
     }
 }
 
+impl<const N: usize, const P: bool> Neg for Word<N, P> {
+    type Output = Self;
+
+    /// Negate the word by flipping its sign.
+    ///
+    /// The word is unchanged if it is always positive, i.e.
+    /// `P == true`. See [`Word::flip_sign()`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let word = FullWord::from_i64(42).0;
+    /// assert_eq!((-word).to_i64().0, -42);
+    /// ```
+    fn neg(self) -> Self::Output {
+        let mut word = self;
+        word.flip_sign();
+        word
+    }
+}
+
+impl<const N: usize, const P: bool> fmt::Display for Word<N, P> {
+    /// Format the word in the classic MIX notation, e.g.
+    /// `+ 001 002 003 004 005`.
+    ///
+    /// Bytes take three digits, so that columns line up for any
+    /// byte value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let word = FullWord::from_bytes([1, 1, 2, 3, 4, 250]);
+    /// assert_eq!(word.to_string(), "- 001 002 003 004 250");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.is_positive() { "+" } else { "-" })?;
+        for byte in &self.data[1..] {
+            write!(f, " {:03}", byte)?;
+        }
+        Ok(())
+    }
+}
+
 impl<const N: usize, const P: bool> Index<RangeInclusive<usize>> for Word<N, P> {
     type Output = [u8];
 
@@ -361,6 +1022,76 @@ impl<const N: usize, const P: bool> IndexMut<usize> for Word<N, P> {
     }
 }
 
+impl<const N: usize, const P: bool> Add for Word<N, P> {
+    type Output = Self;
+
+    /// Add two words in sign-magnitude semantics.
+    ///
+    /// Overflows panic in debug builds and are truncated otherwise.
+    /// See [`Word::checked_add()`] for a non-panicking alternative.
+    fn add(self, rhs: Self) -> Self::Output {
+        let (word, overflow) = self.overflowing_op(rhs, |a, b| a + b);
+        debug_assert!(!overflow, "attempt to add with overflow");
+        word
+    }
+}
+
+impl<const N: usize, const P: bool> Sub for Word<N, P> {
+    type Output = Self;
+
+    /// Subtract two words in sign-magnitude semantics.
+    ///
+    /// Overflows panic in debug builds and are truncated otherwise.
+    /// See [`Word::checked_sub()`] for a non-panicking alternative.
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (word, overflow) = self.overflowing_op(rhs, |a, b| a - b);
+        debug_assert!(!overflow, "attempt to subtract with overflow");
+        word
+    }
+}
+
+impl<const N: usize, const P: bool> Mul for Word<N, P> {
+    type Output = Self;
+
+    /// Multiply two words in sign-magnitude semantics.
+    ///
+    /// Overflows panic in debug builds and are truncated otherwise.
+    /// See [`Word::checked_mul()`] for a non-panicking alternative.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (word, overflow) = self.overflowing_op(rhs, |a, b| a * b);
+        debug_assert!(!overflow, "attempt to multiply with overflow");
+        word
+    }
+}
+
+impl<const N: usize, const P: bool> TryFrom<i64> for Word<N, P> {
+    type Error = OverflowError;
+
+    /// Convert an `i64` to a [`Word`], failing if it is too large.
+    ///
+    /// This is the same as [`Word::from_i64_checked()`].
+    ///
+    /// # Arguments
+    /// * `value` - The value to convert.
+    ///
+    /// # Returns
+    /// * [`Ok(Word)`] - The converted word.
+    /// * [`Err(OverflowError)`] - The magnitude of `value` exceeds
+    ///   the largest one the word can hold.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let word: FullWord = 2000i64.try_into().unwrap();
+    /// assert_eq!(word[..], [0, 0, 0, 0, 0x07, 0xD0]);
+    /// assert!(HalfWord::try_from(0x10000i64).is_err());
+    /// ```
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Self::from_i64_checked(value)
+    }
+}
+
 impl From<Instruction> for Word<6, false> {
     /// Convert an [`Instruction`] to a [`Word<6, false>`].
     ///
@@ -393,6 +1124,109 @@ impl From<Instruction> for Word<6, false> {
     }
 }
 
+impl Word<6, false> {
+    /// Decode the word as an [`Instruction`].
+    ///
+    /// This is a shortcut for [`Instruction::try_from()`].
+    ///
+    /// # Returns
+    /// * [`Ok(Instruction)`] - The decoded instruction.
+    /// * [`Err(())`] - The word holds an unknown opcode.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let word = FullWord::from_bytes([0, 0x07, 0xD0, 0x02, 0x03, 0x08]);
+    /// let instr = word.as_instruction().unwrap();
+    /// assert_eq!(instr.opcode, Opcode::LdA);
+    /// ```
+    pub fn as_instruction(&self) -> Result<Instruction, ()> {
+        Instruction::try_from(*self)
+    }
+
+    /// Join the magnitudes of two words into a single value, like
+    /// `rA` and `rX` in the 10-byte register `rAX`.
+    ///
+    /// Signs are ignored.
+    ///
+    /// # Arguments
+    /// * `a` - The word holding the 5 most significant bytes.
+    /// * `x` - The word holding the 5 least significant bytes.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let a = FullWord::from_bytes([1, 0, 0, 0, 0, 1]);
+    /// let x = FullWord::from_bytes([0, 0, 0, 0, 0, 2]);
+    /// assert_eq!(FullWord::concat_ax(&a, &x), 0x01_00_00_00_00_02);
+    /// ```
+    pub fn concat_ax(a: &Self, x: &Self) -> u128 {
+        a[1..=5]
+            .iter()
+            .chain(&x[1..=5])
+            .fold(0, |value, &byte| (value << 8) | byte as u128)
+    }
+
+    /// Split a value into two positive words, like `rA` and `rX`
+    /// in the 10-byte register `rAX`.
+    ///
+    /// This is the inverse of [`Word::concat_ax()`]. Bytes beyond
+    /// the 10 least significant ones are dropped.
+    ///
+    /// # Arguments
+    /// * `value` - The value to split.
+    ///
+    /// # Returns
+    /// * `(FullWord, FullWord)` - The words holding the 5 most and
+    ///   the 5 least significant bytes.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let (a, x) = FullWord::split_ax(0x01_00_00_00_00_02);
+    /// assert_eq!(a[..], [0, 0, 0, 0, 0, 1]);
+    /// assert_eq!(x[..], [0, 0, 0, 0, 0, 2]);
+    /// ```
+    pub fn split_ax(value: u128) -> (Self, Self) {
+        let bytes = value.to_be_bytes();
+        let mut a = Self::new();
+        let mut x = Self::new();
+        a[1..=5].copy_from_slice(&bytes[6..=10]);
+        x[1..=5].copy_from_slice(&bytes[11..=15]);
+        (a, x)
+    }
+
+    /// Pack five characters into a positive word using [`Alphabet`].
+    ///
+    /// # Arguments
+    /// * `chars` - The characters to put into bytes `1..=5`.
+    ///
+    /// # Returns
+    /// * [`Ok(FullWord)`] - The packed word.
+    /// * [`Err(char)`] - The first character not in [`Alphabet`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let word = FullWord::from_chars(['A', 'B', ' ', '1', '.']).unwrap();
+    /// assert_eq!(word[..], [0, 1, 2, 0, 31, 40]);
+    /// assert_eq!(FullWord::from_chars(['a', 'B', 'C', 'D', 'E']), Err('a'));
+    /// ```
+    pub fn from_chars(chars: [char; 5]) -> Result<Self, char> {
+        let mut word = Self::new();
+        for (byte, ch) in word[1..=5].iter_mut().zip(chars) {
+            *byte = Alphabet::try_from(ch)
+                .and_then(u8::try_from)
+                .map_err(|_| ch)?;
+        }
+        Ok(word)
+    }
+}
+
 /// Alias for a 6-byte [`Word`] including a sign byte.
 pub type FullWord = Word<6, false>;
 
@@ -410,6 +1244,15 @@ pub type PosHalfWord = Word<3, true>;
 pub struct Mem {
     /// The memory area.
     data: [FullWord; Self::SIZE],
+
+    /// Bitset of locations written since creation.
+    initialized: [u64; Self::INIT_WORDS],
+
+    /// Count of words read by the machine.
+    read_count: u64,
+
+    /// Count of words written by the machine.
+    write_count: u64,
 }
 
 impl Mem {
@@ -430,11 +1273,153 @@ impl Mem {
     pub const fn new() -> Self {
         Mem {
             data: [FullWord::new(); 4000],
+            initialized: [0; Self::INIT_WORDS],
+            read_count: 0,
+            write_count: 0,
         }
     }
 
     /// Number of words in the memory area.
     pub const SIZE: usize = 4000;
+
+    /// Number of [`u64`]s needed to track initialized locations.
+    const INIT_WORDS: usize = Self::SIZE.div_ceil(64);
+
+    /// Check whether a location is inside the memory area.
+    ///
+    /// # Arguments
+    /// * `addr` - The location to check.
+    ///
+    /// # Returns
+    /// * `true` - The location is below [`Mem::SIZE`].
+    /// * `false` - The location is out of range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// assert!(Mem::in_bounds(3999));
+    /// assert!(!Mem::in_bounds(4000));
+    /// ```
+    pub const fn in_bounds(addr: u16) -> bool {
+        (addr as usize) < Self::SIZE
+    }
+
+    /// Check whether a location has been written since the memory
+    /// area was created.
+    ///
+    /// Any mutable access through indexing counts as a write.
+    ///
+    /// # Arguments
+    /// * `addr` - The location to check.
+    ///
+    /// # Returns
+    /// * `true` - The location has been written.
+    /// * `false` - The location is untouched or out of range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mem = Mem::new();
+    /// assert!(!mem.is_initialized(10));
+    /// mem[10] = FullWord::from_i64(1).0;
+    /// assert!(mem.is_initialized(10));
+    /// ```
+    pub const fn is_initialized(&self, addr: u16) -> bool {
+        let in_bounds = Self::in_bounds(addr);
+        let addr = addr as usize;
+        in_bounds && (self.initialized[addr / 64] >> (addr % 64)) & 1 == 1
+    }
+
+    /// Mark a range of locations as initialized.
+    fn mark_initialized(&mut self, range: Range<usize>) {
+        for addr in range {
+            self.initialized[addr / 64] |= 1 << (addr % 64);
+        }
+    }
+
+    /// Set whether a location is initialized, e.g. to undo a write.
+    #[cfg(feature = "std")]
+    pub(crate) fn set_initialized(&mut self, addr: u16, initialized: bool) {
+        let addr = addr as usize;
+        if initialized {
+            self.initialized[addr / 64] |= 1 << (addr % 64);
+        } else {
+            self.initialized[addr / 64] &= !(1 << (addr % 64));
+        }
+    }
+
+    /// Get the count of words read by the machine.
+    ///
+    /// Instruction fetches count as reads. Accesses through indexing
+    /// from the host are not counted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.reset_and_run();
+    /// mix.step().unwrap();
+    /// assert_eq!(mix.mem.read_count(), 1);
+    /// ```
+    pub const fn read_count(&self) -> u64 {
+        self.read_count
+    }
+
+    /// Get the count of words written by the machine.
+    ///
+    /// Accesses through indexing from the host are not counted.
+    pub const fn write_count(&self) -> u64 {
+        self.write_count
+    }
+
+    /// Reset the read and write counts to zero.
+    pub fn clear_access_counts(&mut self) {
+        self.read_count = 0;
+        self.write_count = 0;
+    }
+
+    /// Count words read by the machine.
+    pub(crate) fn count_reads(&mut self, count: usize) {
+        self.read_count += count as u64;
+    }
+
+    /// Count words written by the machine.
+    pub(crate) fn count_writes(&mut self, count: usize) {
+        self.write_count += count as u64;
+    }
+
+    /// Find all locations holding an instruction with the given opcode.
+    ///
+    /// Words that cannot be decoded into an [`Instruction`] are skipped.
+    ///
+    /// # Arguments
+    /// * `op` - The opcode to search for.
+    ///
+    /// # Returns
+    /// * [`Iterator<Item = u16>`] - Addresses of matching words, in ascending order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mem = Mem::new();
+    /// mem[10] = Instruction::new(0, 0, 0, Opcode::Jmp).into();
+    ///
+    /// let found: Vec<u16> = mem.find_opcode(Opcode::Jmp).collect();
+    /// assert_eq!(found, [10]);
+    /// ```
+    pub fn find_opcode(&self, op: Opcode) -> impl Iterator<Item = u16> + '_ {
+        self.data
+            .iter()
+            .enumerate()
+            .filter(move |(_, &word)| {
+                Instruction::try_from(word).is_ok_and(|instr| instr.opcode == op)
+            })
+            .map(|(addr, _)| addr as u16)
+    }
 }
 
 impl Index<u16> for Mem {
@@ -449,7 +1434,9 @@ impl Index<u16> for Mem {
 impl IndexMut<u16> for Mem {
     /// Access the mutable word at a memory location.
     fn index_mut(&mut self, index: u16) -> &mut Self::Output {
-        &mut self.data[index as usize]
+        let index = index as usize;
+        self.mark_initialized(index..(index + 1).min(Self::SIZE));
+        &mut self.data[index]
     }
 }
 
@@ -465,6 +1452,7 @@ impl Index<Range<usize>> for Mem {
 impl IndexMut<Range<usize>> for Mem {
     /// Access the mutable word at a range.
     fn index_mut(&mut self, index: Range<usize>) -> &mut Self::Output {
+        self.mark_initialized(index.start..index.end.min(Self::SIZE));
         &mut self.data[index]
     }
 }
@@ -1,4 +1,27 @@
 use core::cmp::Ordering;
+use core::ops::Range;
+#[cfg(feature = "std")]
+use std::boxed::Box;
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+#[cfg(feature = "std")]
+use std::format;
+#[cfg(feature = "std")]
+use std::io::Write;
+#[cfg(feature = "std")]
+use std::rc::Rc;
+#[cfg(feature = "std")]
+use std::string::String;
+#[cfg(feature = "std")]
+use std::sync::atomic;
+#[cfg(feature = "std")]
+use std::sync::atomic::AtomicBool;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+#[cfg(feature = "std")]
+use std::vec::Vec;
 
 use crate::*;
 
@@ -28,6 +51,16 @@ pub enum ErrorCode {
 
     /// The machine is not running and must be [`MixVM::restart()`]ed.
     Halted,
+
+    /// No step is recorded to be reverted.
+    NoHistory,
+
+    /// A memory location is read before ever being written,
+    /// while [`MixVM::strict_uninit`] is set.
+    UninitializedRead,
+
+    /// A value does not fit in a word.
+    Overflow,
 }
 
 /// Values of the comparison indicator in [`MixVM`].
@@ -50,6 +83,34 @@ pub enum CompIndicator {
     Unordered,
 }
 
+impl CompIndicator {
+    /// Compare two integers as MIX does.
+    ///
+    /// Since integers carry no sign for zero, `+0` and `-0` are
+    /// equal as required.
+    ///
+    /// # Arguments
+    /// * `a` - The former operand.
+    /// * `b` - The latter operand.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// assert_eq!(CompIndicator::from_i64_pair(-3, 2), CompIndicator::Less);
+    /// assert_eq!(CompIndicator::from_i64_pair(5, 5), CompIndicator::Equal);
+    /// ```
+    pub const fn from_i64_pair(a: i64, b: i64) -> Self {
+        if a == b {
+            CompIndicator::Equal
+        } else if a < b {
+            CompIndicator::Less
+        } else {
+            CompIndicator::Greater
+        }
+    }
+}
+
 impl Default for CompIndicator {
     /// Get the default value of the comparison indicator.
     fn default() -> Self {
@@ -57,6 +118,485 @@ impl Default for CompIndicator {
     }
 }
 
+/// Integer arithmetic modes of [`MixVM`].
+///
+/// Affects how [`ADD`][Opcode::Add] and [`SUB`][Opcode::Sub]
+/// handle results too large for `rA`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ArithMode {
+    /// Keep the less significant bytes of the result.
+    Modular,
+
+    /// Clamp the result to the largest magnitude `rA` can hold.
+    Saturating,
+}
+
+impl Default for ArithMode {
+    /// Get the default arithmetic mode.
+    fn default() -> Self {
+        ArithMode::Modular
+    }
+}
+
+/// Registers in [`MixVM`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Register {
+    /// The register `rA`.
+    A,
+
+    /// The register `rX`.
+    X,
+
+    /// The register `rI1`.
+    I1,
+
+    /// The register `rI2`.
+    I2,
+
+    /// The register `rI3`.
+    I3,
+
+    /// The register `rI4`.
+    I4,
+
+    /// The register `rI5`.
+    I5,
+
+    /// The register `rI6`.
+    I6,
+
+    /// The register `rJ`.
+    J,
+}
+
+/// Events happening in [`MixVM`] while executing instructions.
+///
+/// Events are only recorded if [`MixVM::recording`] is `true`.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MachineEvent {
+    /// A register is written with a new value.
+    ///
+    /// Values of `rI1-6` and `rJ` are padded as `[S, 0, 0, 0, B1, B2]`.
+    RegisterWrite {
+        /// The register written.
+        register: Register,
+
+        /// The new value of the register.
+        value: FullWord,
+    },
+
+    /// A memory cell is written with a new value.
+    MemoryWrite {
+        /// The address of the cell.
+        addr: u16,
+
+        /// The new value of the cell.
+        value: FullWord,
+    },
+
+    /// A jump is taken.
+    Jump {
+        /// The address of the jump instruction.
+        from: u16,
+
+        /// The address jumped to.
+        to: u16,
+    },
+
+    /// The overflow toggle is turned on.
+    OverflowSet,
+
+    /// An IO operation is done by a device.
+    DeviceIO {
+        /// The unit of the device.
+        unit: u8,
+
+        /// The operation, one of `IN`, `OUT` and `IOC`.
+        opcode: Opcode,
+    },
+}
+
+/// Suspicious but non-fatal things happening in [`MixVM`] while
+/// executing instructions.
+///
+/// See [`MixVM::warnings`].
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RuntimeWarning {
+    /// A memory location is read before ever being written, while
+    /// [`MixVM::strict_uninit`] is not set.
+    UninitializedRead {
+        /// The location of the instruction.
+        pc: u16,
+
+        /// The location read.
+        addr: u16,
+    },
+
+    /// A value loaded into an index register does not fit in it,
+    /// and its leading bytes are dropped.
+    IndexTruncated {
+        /// The location of the instruction.
+        pc: u16,
+
+        /// The index register, `1` to `6`.
+        index: u8,
+    },
+
+    /// A jump lands on a word that is not a valid instruction.
+    JumpToData {
+        /// The location of the jump instruction.
+        pc: u16,
+
+        /// The location jumped to.
+        to: u16,
+    },
+}
+
+/// Snapshot of registers and flags in [`MixVM`].
+///
+/// # Example
+/// ```rust
+/// use mixture::*;
+///
+/// let mut mix = MixVM::new();
+/// mix.r_a = FullWord::from_i64(42).0;
+///
+/// let expected = Registers {
+///     r_a: FullWord::from_i64(42).0,
+///     ..Default::default()
+/// };
+/// assert_eq!(mix.registers(), expected);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Registers {
+    /// The register `rA`.
+    pub r_a: FullWord,
+
+    /// The register `rX`.
+    pub r_x: FullWord,
+
+    /// The register `rIn`, where `n = 1, 2, 3, 4, 5, 6`.
+    /// `r_in[0]` should always be 0.
+    pub r_in: [HalfWord; 7],
+
+    /// The register `rJ`.
+    pub r_j: PosHalfWord,
+
+    /// The overflow toggle.
+    pub overflow: bool,
+
+    /// The comparison indicator.
+    pub comp: CompIndicator,
+}
+
+#[cfg(feature = "std")]
+impl Registers {
+    /// Get all registers as [`FullWord`]s.
+    ///
+    /// Values of `rI1-6` and `rJ` are padded as `[S, 0, 0, 0, B1, B2]`.
+    fn to_padded_words(self) -> [(Register, FullWord); 9] {
+        let pad = |reg: &HalfWord| FullWord::from_bytes([reg[0], 0, 0, 0, reg[1], reg[2]]);
+        [
+            (Register::A, self.r_a),
+            (Register::X, self.r_x),
+            (Register::I1, pad(&self.r_in[1])),
+            (Register::I2, pad(&self.r_in[2])),
+            (Register::I3, pad(&self.r_in[3])),
+            (Register::I4, pad(&self.r_in[4])),
+            (Register::I5, pad(&self.r_in[5])),
+            (Register::I6, pad(&self.r_in[6])),
+            (
+                Register::J,
+                FullWord::from_bytes([0, 0, 0, 0, self.r_j[1], self.r_j[2]]),
+            ),
+        ]
+    }
+}
+
+/// A difference between two machines found by [`MixVM::diff()`].
+///
+/// `left` is the value in the machine `diff()` is called on, and
+/// `right` is the value in the other one.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StateDifference {
+    /// A register differs.
+    ///
+    /// Values of `rI1-6` and `rJ` are padded as `[S, 0, 0, 0, B1, B2]`.
+    Register {
+        /// The register.
+        register: Register,
+
+        /// The value on the left.
+        left: FullWord,
+
+        /// The value on the right.
+        right: FullWord,
+    },
+
+    /// The overflow toggle differs.
+    Overflow {
+        /// The value on the left.
+        left: bool,
+
+        /// The value on the right.
+        right: bool,
+    },
+
+    /// The comparison indicator differs.
+    Comp {
+        /// The value on the left.
+        left: CompIndicator,
+
+        /// The value on the right.
+        right: CompIndicator,
+    },
+
+    /// The instruction pointer differs.
+    Pc {
+        /// The value on the left.
+        left: u16,
+
+        /// The value on the right.
+        right: u16,
+    },
+
+    /// The running state differs.
+    Halted {
+        /// The value on the left.
+        left: bool,
+
+        /// The value on the right.
+        right: bool,
+    },
+
+    /// A memory cell differs.
+    Memory {
+        /// The address of the cell.
+        addr: u16,
+
+        /// The value on the left.
+        left: FullWord,
+
+        /// The value on the right.
+        right: FullWord,
+    },
+}
+
+/// Counts of memory accesses made by [`MixVM`].
+///
+/// See [`MixVM::memory_access_stats()`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct MemAccessStats {
+    /// Count of words read, including instruction fetches.
+    pub reads: u64,
+
+    /// Count of words written.
+    pub writes: u64,
+}
+
+/// Reasons for a [`MixVM`] to halt.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HaltReason {
+    /// A `HLT` instruction is executed.
+    Hlt,
+
+    /// A `HLT` instruction is executed with the overflow toggle
+    /// still on, while [`MixVM::warn_overflow_on_halt`] is set.
+    HltWithOverflow,
+
+    /// A `HLT` instruction is executed while the device at the unit
+    /// is still busy.
+    PendingIO(u8),
+
+    /// An instruction failed with the error.
+    Error(ErrorCode),
+}
+
+/// Reasons for [`MixVM::run_until_halt()`] to return.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StopReason {
+    /// The machine is halted. See [`MixVM::halt_reason`] for details.
+    Halted,
+
+    /// The run is interrupted through [`MixVM::interrupt_handle()`].
+    /// The machine is still running and can be resumed.
+    Interrupted,
+
+    /// The step budget of [`MixVM::run_program()`] is used up.
+    /// The machine is still running and can be resumed.
+    StepLimit,
+}
+
+/// Summary of a [`MixVM::run_until_halt()`] or
+/// [`MixVM::run_program()`] call.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[must_use]
+pub struct RunReport {
+    /// Number of instructions run.
+    pub steps: usize,
+
+    /// The reason for the run to stop.
+    pub stop: StopReason,
+}
+
+/// A problem found by [`MixVM::validate_program()`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ProgramWarning {
+    /// The location of the instruction.
+    pub addr: u16,
+
+    /// The error the instruction is expected to cause. One of
+    /// [`ErrorCode::IllegalInstruction`], [`ErrorCode::InvalidField`]
+    /// and [`ErrorCode::InvalidAddress`].
+    pub error: ErrorCode,
+}
+
+/// A host function handling `TRAP` in [`MixVM`].
+///
+/// It is called with the machine and the indexed address `M` of the
+/// instruction. See [`MixVM::set_trap_handler()`].
+#[cfg(feature = "std")]
+pub type TrapHandler = dyn FnMut(&mut MixVM, i16) -> Result<(), ErrorCode>;
+
+/// A host function observing each instruction run by [`MixVM`].
+///
+/// It is called with the machine after the instruction, the location
+/// of the instruction and the instruction itself. See
+/// [`MixVM::set_trace_hook()`].
+#[cfg(feature = "std")]
+pub type TraceHook = dyn FnMut(&MixVM, u16, &Instruction);
+
+/// A host function called when [`MixVM`] executes `HLT`.
+///
+/// See [`MixVM::set_halt_hook()`].
+#[cfg(feature = "std")]
+pub type HaltHook = dyn FnMut(&MixVM);
+
+/// A [`MixVM`] logging each instruction run as a line of text.
+///
+/// The log has the format of [`MixVM::trace_to_writer()`], naming
+/// instructions by their MIX mnemonics. It only depends on the
+/// program and the state of the machine, so it can be compared
+/// against expected logs in tests.
+///
+/// See [`MixVM::into_event_recorder()`].
+#[cfg(feature = "std")]
+pub struct EventRecorder {
+    /// The machine being recorded.
+    mix: MixVM,
+
+    /// The log written so far.
+    log: Rc<RefCell<Vec<u8>>>,
+}
+
+#[cfg(feature = "std")]
+impl EventRecorder {
+    /// Get the machine being recorded.
+    pub fn machine(&self) -> &MixVM {
+        &self.mix
+    }
+
+    /// Get the machine being recorded, e.g. to run it.
+    pub fn machine_mut(&mut self) -> &mut MixVM {
+        &mut self.mix
+    }
+
+    /// Get the log written so far.
+    pub fn log(&self) -> String {
+        String::from_utf8_lossy(&self.log.borrow()).into_owned()
+    }
+
+    /// Take the machine back, together with the log.
+    ///
+    /// The trace hook logging to the recorder is removed.
+    pub fn into_parts(mut self) -> (MixVM, String) {
+        let log = self.log();
+        self.mix.trace_hook = None;
+        (self.mix, log)
+    }
+}
+
+/// A writer appending to a shared buffer.
+#[cfg(feature = "std")]
+struct SharedLog(Rc<RefCell<Vec<u8>>>);
+
+#[cfg(feature = "std")]
+impl Write for SharedLog {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Undo record of a single step of [`MixVM`].
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+struct StepRecord {
+    r_a: FullWord,
+    r_x: FullWord,
+    r_in: [HalfWord; 7],
+    r_j: PosHalfWord,
+    overflow: bool,
+    comp: CompIndicator,
+    pc: u16,
+    halted: bool,
+
+    /// Address of the first saved memory cell, before wrapping
+    /// around the end of memory.
+    mem_start: usize,
+
+    /// Memory cells possibly written by the step.
+    mem_words: Vec<FullWord>,
+
+    /// Whether each saved memory cell was initialized.
+    mem_initialized: Vec<bool>,
+}
+
+#[cfg(feature = "std")]
+impl StepRecord {
+    /// Get the saved registers and flags.
+    fn registers(&self) -> Registers {
+        Registers {
+            r_a: self.r_a,
+            r_x: self.r_x,
+            r_in: self.r_in,
+            r_j: self.r_j,
+            overflow: self.overflow,
+            comp: self.comp,
+        }
+    }
+
+    /// Get the saved memory cells with their locations.
+    fn mem_cells(&self) -> impl Iterator<Item = (u16, FullWord)> + '_ {
+        self.mem_words
+            .iter()
+            .enumerate()
+            .map(|(i, &word)| (((self.mem_start + i) % Mem::SIZE) as u16, word))
+    }
+}
+
+/// Changes made by a single step of [`MixVM`].
+///
+/// See [`MixVM::step_with_delta()`].
+#[cfg(feature = "std")]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct StepDelta {
+    /// The location of the instruction run.
+    pub pc: u16,
+
+    /// The changes, with `left` holding the value before the step
+    /// and `right` the one after it. They are in the same order as
+    /// in [`MixVM::diff()`].
+    pub changes: Vec<StateDifference>,
+}
+
 /// The state of a MIX machine.
 ///
 /// # Example
@@ -100,12 +640,105 @@ pub struct MixVM {
     /// The machine running state.
     pub halted: bool,
 
+    /// Why the machine halted last, if it halted by itself.
+    ///
+    /// Cleared by [`MixVM::restart()`].
+    pub halt_reason: Option<HaltReason>,
+
+    /// Location of the instruction that failed, if the machine
+    /// halted with an error. `pc` may already be past it.
+    ///
+    /// Cleared by [`MixVM::restart()`].
+    pub fault_pc: Option<u16>,
+
+    /// Location of the instruction last run by [`MixVM::step()`],
+    /// together with the instruction.
+    ///
+    /// Cleared by [`MixVM::reset()`].
+    pub last_executed: Option<(u16, Instruction)>,
+
+    /// Whether `HLT` reports [`HaltReason::HltWithOverflow`] if the
+    /// overflow toggle is on.
+    pub warn_overflow_on_halt: bool,
+
+    /// The integer arithmetic mode.
+    pub arith_mode: ArithMode,
+
+    /// Whether reading a never-written memory location is an error.
+    pub strict_uninit: bool,
+
+    /// Whether `MOVE`, `IN` and `OUT` targets wrap around modulo
+    /// [`Mem::SIZE`] instead of failing at the end of memory.
+    pub address_wrap: bool,
+
+    /// Whether `pc` wraps around to 0 after the instruction at the
+    /// end of memory, instead of the next fetch failing with
+    /// [`ErrorCode::InvalidAddress`].
+    pub pc_wrap: bool,
+
     /// IO devices.
     #[cfg(feature = "io")]
     pub io_devices: [Option<std::boxed::Box<dyn io::IODevice>>; 21],
+
+    /// Whether to record [`MachineEvent`]s into [`MixVM::events`].
+    #[cfg(feature = "std")]
+    pub recording: bool,
+
+    /// Recorded events, oldest first.
+    #[cfg(feature = "std")]
+    pub events: Vec<MachineEvent>,
+
+    /// Whether to collect [`RuntimeWarning`]s into [`MixVM::warnings`].
+    #[cfg(feature = "std")]
+    pub collect_warnings: bool,
+
+    /// Warnings found while running, oldest first.
+    ///
+    /// The list may be cleared at any time. Cleared by
+    /// [`MixVM::reset()`].
+    #[cfg(feature = "std")]
+    pub warnings: Vec<RuntimeWarning>,
+
+    /// Recorded steps to be reverted, oldest first.
+    #[cfg(feature = "std")]
+    history: VecDeque<StepRecord>,
+
+    /// Maximum count of recorded steps. `0` disables recording.
+    #[cfg(feature = "std")]
+    history_max: usize,
+
+    /// Handler of `TRAP`.
+    #[cfg(feature = "std")]
+    trap_handler: Option<Box<TrapHandler>>,
+
+    /// Hook called after each step.
+    #[cfg(feature = "std")]
+    trace_hook: Option<Box<TraceHook>>,
+
+    /// Hook called on `HLT`.
+    #[cfg(feature = "std")]
+    halt_hook: Option<Box<HaltHook>>,
+
+    /// Flag to interrupt [`MixVM::run_until_halt()`].
+    #[cfg(feature = "std")]
+    interrupt: Arc<AtomicBool>,
+
+    /// Whether the last instruction jumped.
+    jumped: bool,
+
+    /// Whether the running instruction has a negative sign. This
+    /// tells `-0` from `+0` in its address.
+    instr_negative: bool,
+
+    /// Location of the running instruction. `pc` may have wrapped
+    /// around past it.
+    instr_pc: u16,
 }
 
 impl MixVM {
+    /// The field of `Special` for `TRAP`.
+    pub const TRAP_FIELD: u8 = 63;
+
     /// Create a new MIX machine.
     pub fn new() -> Self {
         MixVM {
@@ -118,16 +751,93 @@ impl MixVM {
             mem: Mem::new(),
             pc: 0,
             halted: true,
+            halt_reason: None,
+            fault_pc: None,
+            last_executed: None,
+            warn_overflow_on_halt: false,
+            arith_mode: Default::default(),
+            strict_uninit: false,
+            address_wrap: false,
+            pc_wrap: false,
 
             #[cfg(feature = "io")]
             io_devices: Default::default(),
+
+            #[cfg(feature = "std")]
+            recording: false,
+            #[cfg(feature = "std")]
+            events: Vec::new(),
+            #[cfg(feature = "std")]
+            collect_warnings: false,
+            #[cfg(feature = "std")]
+            warnings: Vec::new(),
+            #[cfg(feature = "std")]
+            history: VecDeque::new(),
+            #[cfg(feature = "std")]
+            history_max: 0,
+            #[cfg(feature = "std")]
+            trap_handler: None,
+            #[cfg(feature = "std")]
+            trace_hook: None,
+            #[cfg(feature = "std")]
+            halt_hook: None,
+            #[cfg(feature = "std")]
+            interrupt: Arc::new(AtomicBool::new(false)),
+
+            jumped: false,
+            instr_negative: false,
+            instr_pc: 0,
+        }
+    }
+
+    /// Create a new MIX machine with all of memory loaded from an
+    /// image.
+    ///
+    /// The image holds the 6 bytes of each word, sign first, for
+    /// every location in order.
+    ///
+    /// # Arguments
+    /// * `image` - The memory image of `6 * Mem::SIZE` bytes.
+    ///
+    /// # Returns
+    /// * [`Ok(MixVM)`] - The new machine.
+    /// * [`Err(())`] - The image has a wrong length, or a sign byte is
+    ///   neither [`FullWord::POS`] nor [`FullWord::NEG`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut image = vec![0; 6 * Mem::SIZE];
+    /// image[6..12].copy_from_slice(&[1, 0, 0, 0, 0, 42]);
+    ///
+    /// let mix = MixVM::with_memory_image(&image).unwrap();
+    /// assert_eq!(mix.mem[1].to_i64().0, -42);
+    /// assert!(MixVM::with_memory_image(&image[6..]).is_err());
+    /// ```
+    pub fn with_memory_image(image: &[u8]) -> Result<Self, ()> {
+        if image.len() != 6 * Mem::SIZE {
+            return Err(());
+        }
+        let mut mix = Self::new();
+        for (addr, bytes) in image.chunks_exact(6).enumerate() {
+            if bytes[0] != FullWord::POS && bytes[0] != FullWord::NEG {
+                return Err(());
+            }
+            mix.mem[addr as u16].set_all(bytes.try_into().unwrap());
         }
+        Ok(mix)
     }
 
     /// Reset the machine.
     ///
     /// This method resets the machine to its initial state,
-    /// clearing the registers.
+    /// clearing the registers and memory access counts. Installed
+    /// IO devices are reset with `IODevice::reset()`.
+    ///
+    /// The running state is kept, so a halted machine still needs
+    /// [`MixVM::restart()`]. See [`MixVM::reset_and_run()`] to do
+    /// both.
     pub fn reset(&mut self) {
         self.r_a = Default::default();
         self.r_x = Default::default();
@@ -136,6 +846,38 @@ impl MixVM {
         self.pc = 0;
         self.overflow = false;
         self.comp = Default::default();
+        self.last_executed = None;
+        self.mem.clear_access_counts();
+        #[cfg(feature = "io")]
+        for dev in self.io_devices.iter_mut().flatten() {
+            dev.reset();
+        }
+        #[cfg(feature = "std")]
+        self.warnings.clear();
+        // Steps before a reset are not meant to be reverted.
+        #[cfg(feature = "std")]
+        self.history.clear();
+    }
+
+    /// Reset and restart the machine.
+    ///
+    /// Equivalent to [`MixVM::reset()`] followed by
+    /// [`MixVM::restart()`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.pc = 100;
+    ///
+    /// mix.reset_and_run();
+    /// assert_eq!(mix.pc, 0);
+    /// assert!(mix.is_running());
+    /// ```
+    pub fn reset_and_run(&mut self) {
+        self.reset();
+        self.restart();
     }
 
     /// Restart the machine.
@@ -143,113 +885,1569 @@ impl MixVM {
     /// This function un-halts the machine.
     pub fn restart(&mut self) {
         self.halted = false;
+        self.halt_reason = None;
+        self.fault_pc = None;
+    }
+
+    /// Clear the memory.
+    ///
+    /// All words are set to `+0` and marked as never written, as
+    /// checked by [`MixVM::strict_uninit`].
+    pub fn clear_memory(&mut self) {
+        self.mem = Mem::new();
+    }
+
+    /// Load a program into memory from a fixed-size array.
+    ///
+    /// This does not need an allocator, and is thus usable without
+    /// `std`. Neither `pc` nor the running state is changed.
+    ///
+    /// # Arguments
+    /// * `origin` - The location of the first word.
+    /// * `words` - The words to load.
+    ///
+    /// # Returns
+    /// * [`Ok(())`] - The program is loaded.
+    /// * [`Err(ErrorCode::InvalidAddress)`] - The program does not fit
+    ///   in memory. The memory is left untouched.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.load_program_array(
+    ///     100,
+    ///     [
+    ///         Instruction::new(0, 0, 0, Opcode::Nop).into(),
+    ///         Instruction::new(0, 2, 0, Opcode::Special).into(),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(mix.mem[101][..], [0, 0, 0, 0, 2, 5]);
+    /// ```
+    pub fn load_program_array<const M: usize>(
+        &mut self,
+        origin: u16,
+        words: [FullWord; M],
+    ) -> Result<(), ErrorCode> {
+        let start = origin as usize;
+        if start + M > Mem::SIZE {
+            return Err(ErrorCode::InvalidAddress);
+        }
+        self.mem[start..start + M].copy_from_slice(&words);
+        Ok(())
+    }
+
+    /// Run the next instruction of the machine.
+    ///
+    /// # Returns
+    /// * [`Ok(())`] - The machine successfully completed its operation.
+    /// * [`Err(ErrorCode)`] - The machine encountered an error and is now halted.
+    pub fn step(&mut self) -> Result<(), ErrorCode> {
+        if self.halted {
+            return Err(ErrorCode::Halted);
+        }
+
+        #[cfg(feature = "std")]
+        let record = self.helper_make_record();
+
+        let fetch_pc = self.pc;
+        let result = self.helper_step();
+        match result {
+            Ok(instr) => {
+                self.last_executed = Some((fetch_pc, instr));
+                #[cfg(feature = "std")]
+                self.helper_trace(fetch_pc, &instr);
+            }
+            Err(err) => {
+                self.halt_reason = Some(HaltReason::Error(err));
+                self.fault_pc = Some(fetch_pc);
+            }
+        }
+
+        #[cfg(feature = "std")]
+        if let Some(record) = record {
+            self.helper_commit_record(record);
+        }
+
+        result.map(|_| ())
+    }
+
+    /// Run the next instruction and report what it changed.
+    ///
+    /// This is the same as [`MixVM::step()`], and additionally
+    /// lists each register, flag and memory cell changed by the
+    /// step with its values before and after it.
+    ///
+    /// # Returns
+    /// * [`Ok(StepDelta)`] - The changes made by the step.
+    /// * [`Err(ErrorCode)`] - The machine encountered an error and is now halted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.mem[0] = Instruction::new(2000, 2, 0, Opcode::ModifyA).into();
+    /// mix.reset_and_run();
+    ///
+    /// let delta = mix.step_with_delta().unwrap();
+    /// assert_eq!(delta.pc, 0);
+    /// assert_eq!(
+    ///     delta.changes[0],
+    ///     StateDifference::Register {
+    ///         register: Register::A,
+    ///         left: FullWord::from_i64(0).0,
+    ///         right: FullWord::from_i64(2000).0,
+    ///     }
+    /// );
+    /// assert_eq!(delta.changes[1], StateDifference::Pc { left: 0, right: 1 });
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn step_with_delta(&mut self) -> Result<StepDelta, ErrorCode> {
+        let before = self.helper_snapshot();
+        self.step()?;
+
+        let mut changes = Vec::new();
+        Self::helper_diff_registers(&before.registers(), &self.registers(), &mut changes);
+        if before.pc != self.pc {
+            changes.push(StateDifference::Pc {
+                left: before.pc,
+                right: self.pc,
+            });
+        }
+        if before.halted != self.halted {
+            changes.push(StateDifference::Halted {
+                left: before.halted,
+                right: self.halted,
+            });
+        }
+        for (addr, left) in before.mem_cells() {
+            let right = self.mem[addr];
+            if left[..] != right[..] {
+                changes.push(StateDifference::Memory { addr, left, right });
+            }
+        }
+        Ok(StepDelta {
+            pc: before.pc,
+            changes,
+        })
+    }
+
+    /// Run the machine until it halts or is interrupted.
+    ///
+    /// With the `std` feature, the run can be interrupted from
+    /// another thread through [`MixVM::interrupt_handle()`].
+    ///
+    /// # Returns
+    /// * [`Ok(RunReport)`] - The number of steps run and the reason to stop.
+    /// * [`Err(ErrorCode)`] - The machine encountered an error and is now halted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.mem[3] = Instruction::new(0, 2, 0, Opcode::Special).into();
+    /// mix.reset_and_run();
+    ///
+    /// let report = mix.run_until_halt().unwrap();
+    /// assert_eq!(report.steps, 4);
+    /// assert_eq!(report.stop, StopReason::Halted);
+    /// assert_eq!(mix.pc, 4);
+    /// ```
+    pub fn run_until_halt(&mut self) -> Result<RunReport, ErrorCode> {
+        self.helper_run(usize::MAX)
+    }
+
+    /// Load a program into cleared memory and run it.
+    ///
+    /// The machine is reset and its memory cleared. Then the words
+    /// are loaded, and the machine runs from `entry` until it halts,
+    /// is interrupted or has run `max_steps` instructions.
+    ///
+    /// # Arguments
+    /// * `words` - The locations and words of the program.
+    /// * `entry` - The location to start at.
+    /// * `max_steps` - The largest number of instructions to run.
+    ///
+    /// # Returns
+    /// * [`Ok(RunReport)`] - The number of steps run and the reason to stop.
+    /// * [`Err(ErrorCode::InvalidAddress)`] - A word is outside memory.
+    /// * [`Err(ErrorCode)`] - The machine encountered an error and is now halted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// let program = [
+    ///     (100, Instruction::new(42, 2, 0, Opcode::ModifyA).into()),
+    ///     (101, Instruction::new(0, 2, 0, Opcode::Special).into()),
+    /// ];
+    ///
+    /// let report = mix.run_program(&program, 100, 10).unwrap();
+    /// assert_eq!(report.steps, 2);
+    /// assert_eq!(report.stop, StopReason::Halted);
+    /// assert_eq!(mix.r_a.to_i64().0, 42);
+    /// ```
+    pub fn run_program(
+        &mut self,
+        words: &[(u16, FullWord)],
+        entry: u16,
+        max_steps: usize,
+    ) -> Result<RunReport, ErrorCode> {
+        if words.iter().any(|&(addr, _)| !Mem::in_bounds(addr)) {
+            return Err(ErrorCode::InvalidAddress);
+        }
+        self.reset();
+        self.clear_memory();
+        for &(addr, word) in words {
+            self.mem[addr] = word;
+        }
+        self.pc = entry;
+        self.restart();
+        self.helper_run(max_steps)
+    }
+
+    /// Run the machine until it halts, is interrupted or has run
+    /// `max_steps` instructions.
+    fn helper_run(&mut self, max_steps: usize) -> Result<RunReport, ErrorCode> {
+        let mut steps = 0;
+        while !self.halted {
+            if steps == max_steps {
+                return Ok(RunReport {
+                    steps,
+                    stop: StopReason::StepLimit,
+                });
+            }
+            #[cfg(feature = "std")]
+            if self.interrupt.swap(false, atomic::Ordering::Relaxed) {
+                return Ok(RunReport {
+                    steps,
+                    stop: StopReason::Interrupted,
+                });
+            }
+            self.step()?;
+            steps += 1;
+        }
+        Ok(RunReport {
+            steps,
+            stop: StopReason::Halted,
+        })
+    }
+
+    /// Get a handle to interrupt [`MixVM::run_until_halt()`].
+    ///
+    /// Setting the flag to `true`, e.g. from another thread, makes
+    /// the run return [`StopReason::Interrupted`] before the next
+    /// step. The flag is cleared when the interruption is reported.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::sync::atomic::Ordering;
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.reset_and_run();
+    ///
+    /// mix.interrupt_handle().store(true, Ordering::Relaxed);
+    /// let report = mix.run_until_halt().unwrap();
+    /// assert_eq!(report.steps, 0);
+    /// assert_eq!(report.stop, StopReason::Interrupted);
+    /// assert!(mix.is_running());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn interrupt_handle(&self) -> Arc<AtomicBool> {
+        self.interrupt.clone()
+    }
+
+    /// Interrupt [`MixVM::run_until_halt()`] before its next step.
+    ///
+    /// See [`MixVM::interrupt_handle()`] to interrupt from another
+    /// thread.
+    #[cfg(feature = "std")]
+    pub fn interrupt(&self) {
+        self.interrupt.store(true, atomic::Ordering::Relaxed);
+    }
+
+    /// Run the next instruction, or a whole run of `NOP`s at once.
+    ///
+    /// If the next instruction is `NOP`, all consecutive `NOP`s are
+    /// run and the machine stops at the first other instruction.
+    /// Otherwise this is the same as [`MixVM::step()`]. Each `NOP` is
+    /// still run as a single step, so events and history are kept.
+    ///
+    /// At most [`Mem::SIZE`] `NOP`s are run at once, so a memory full
+    /// of `NOP`s with [`MixVM::pc_wrap`] set stops where it started.
+    ///
+    /// # Returns
+    /// * [`Ok(())`] - The machine successfully completed its operation.
+    /// * [`Err(ErrorCode)`] - The machine encountered an error and is now halted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.mem[3] = Instruction::new(0, 2, 0, Opcode::Special).into();
+    /// mix.reset_and_run();
+    ///
+    /// mix.step_skipping_nops().unwrap();
+    /// assert_eq!(mix.pc, 3);
+    /// ```
+    pub fn step_skipping_nops(&mut self) -> Result<(), ErrorCode> {
+        let skipping = self.helper_at_nop();
+        self.step()?;
+        let mut count = 1;
+        while skipping && count < Mem::SIZE && !self.halted && self.helper_at_nop() {
+            self.step()?;
+            count += 1;
+        }
+        Ok(())
+    }
+
+    /// Check if the instruction at `pc` is `NOP`.
+    fn helper_at_nop(&self) -> bool {
+        self.current_word()
+            .is_some_and(|word| word[5] == Opcode::Nop as u8)
+    }
+
+    /// Fetch and run the next instruction, returning it.
+    fn helper_step(&mut self) -> Result<Instruction, ErrorCode> {
+        // Fetch the instruction.
+        self.instr_pc = self.pc;
+        let word = self.helper_read_mem(self.pc).inspect_err(|_| {
+            self.halt();
+        })?;
+        let instr: Instruction = word.try_into().map_err(|_| {
+            self.halt();
+            ErrorCode::IllegalInstruction
+        })?;
+        self.instr_negative = !word.is_positive();
+
+        self.pc += 1;
+        if self.pc_wrap && self.pc as usize == Mem::SIZE {
+            self.pc = 0;
+        }
+
+        // Run the instruction.
+        self.helper_dispatch(&instr).inspect_err(|_| {
+            self.halt();
+        })?;
+
+        Ok(instr)
+    }
+
+    /// Run a decoded instruction, with `pc` already past it.
+    fn helper_dispatch(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
+        let instr = *instr;
+        match instr.opcode {
+            Opcode::Nop => self.handle_instr_nop(&instr),
+
+            Opcode::Add => self.handle_instr_add_sub(&instr),
+            Opcode::Sub => self.handle_instr_add_sub(&instr),
+            Opcode::Mul => self.handle_instr_mul(&instr),
+            Opcode::Div => self.handle_instr_div(&instr),
+
+            Opcode::Special => self.handle_instr_special(&instr),
+            Opcode::Shift => self.handle_instr_shift(&instr),
+            Opcode::Move => self.handle_instr_move(&instr),
+
+            Opcode::LdA => self.handle_instr_load_6b(&instr),
+            Opcode::Ld1 => self.handle_instr_load_3b(&instr),
+            Opcode::Ld2 => self.handle_instr_load_3b(&instr),
+            Opcode::Ld3 => self.handle_instr_load_3b(&instr),
+            Opcode::Ld4 => self.handle_instr_load_3b(&instr),
+            Opcode::Ld5 => self.handle_instr_load_3b(&instr),
+            Opcode::Ld6 => self.handle_instr_load_3b(&instr),
+            Opcode::LdX => self.handle_instr_load_6b(&instr),
+
+            Opcode::LdAN => self.handle_instr_load_neg_6b(&instr),
+            Opcode::Ld1N => self.handle_instr_load_neg_3b(&instr),
+            Opcode::Ld2N => self.handle_instr_load_neg_3b(&instr),
+            Opcode::Ld3N => self.handle_instr_load_neg_3b(&instr),
+            Opcode::Ld4N => self.handle_instr_load_neg_3b(&instr),
+            Opcode::Ld5N => self.handle_instr_load_neg_3b(&instr),
+            Opcode::Ld6N => self.handle_instr_load_neg_3b(&instr),
+            Opcode::LdXN => self.handle_instr_load_neg_6b(&instr),
+
+            Opcode::StA => self.handle_instr_store_6b(&instr),
+            Opcode::St1 => self.handle_instr_store_3b(&instr),
+            Opcode::St2 => self.handle_instr_store_3b(&instr),
+            Opcode::St3 => self.handle_instr_store_3b(&instr),
+            Opcode::St4 => self.handle_instr_store_3b(&instr),
+            Opcode::St5 => self.handle_instr_store_3b(&instr),
+            Opcode::St6 => self.handle_instr_store_3b(&instr),
+            Opcode::StX => self.handle_instr_store_6b(&instr),
+            Opcode::StJ => self.handle_instr_store_j(&instr),
+            Opcode::StZ => self.handle_instr_store_zero(&instr),
+
+            Opcode::Jbus => self.handle_instr_jbus_jred(&instr),
+            Opcode::Ioc => self.handle_instr_ioc(&instr),
+            Opcode::In => self.handle_instr_in_out(&instr),
+            Opcode::Out => self.handle_instr_in_out(&instr),
+            Opcode::Jred => self.handle_instr_jbus_jred(&instr),
+            Opcode::Jmp => self.handle_instr_jmp(&instr),
+
+            Opcode::JA => self.handle_instr_jmp_reg_6b(&instr),
+            Opcode::J1 => self.handle_instr_jmp_reg_3b(&instr),
+            Opcode::J2 => self.handle_instr_jmp_reg_3b(&instr),
+            Opcode::J3 => self.handle_instr_jmp_reg_3b(&instr),
+            Opcode::J4 => self.handle_instr_jmp_reg_3b(&instr),
+            Opcode::J5 => self.handle_instr_jmp_reg_3b(&instr),
+            Opcode::J6 => self.handle_instr_jmp_reg_3b(&instr),
+            Opcode::JX => self.handle_instr_jmp_reg_6b(&instr),
+
+            Opcode::ModifyA => self.handle_instr_modify_6b(&instr),
+            Opcode::Modify1 => self.handle_instr_modify_3b(&instr),
+            Opcode::Modify2 => self.handle_instr_modify_3b(&instr),
+            Opcode::Modify3 => self.handle_instr_modify_3b(&instr),
+            Opcode::Modify4 => self.handle_instr_modify_3b(&instr),
+            Opcode::Modify5 => self.handle_instr_modify_3b(&instr),
+            Opcode::Modify6 => self.handle_instr_modify_3b(&instr),
+            Opcode::ModifyX => self.handle_instr_modify_6b(&instr),
+
+            Opcode::CmpA => self.handle_instr_cmp_6b(&instr),
+            Opcode::Cmp1 => self.handle_instr_cmp_3b(&instr),
+            Opcode::Cmp2 => self.handle_instr_cmp_3b(&instr),
+            Opcode::Cmp3 => self.handle_instr_cmp_3b(&instr),
+            Opcode::Cmp4 => self.handle_instr_cmp_3b(&instr),
+            Opcode::Cmp5 => self.handle_instr_cmp_3b(&instr),
+            Opcode::Cmp6 => self.handle_instr_cmp_3b(&instr),
+            Opcode::CmpX => self.handle_instr_cmp_6b(&instr),
+        }
+    }
+
+    /// Run a decoded instruction as if it were at `pc`.
+    ///
+    /// Nothing is fetched from memory, and `pc` is left untouched
+    /// unless the instruction jumps. Jumps save `pc + 1` into `rJ` as
+    /// usual. Unlike [`MixVM::step()`], the machine does not need to be
+    /// running and is not halted on errors.
+    ///
+    /// As an [`Instruction`] cannot hold `-0` as its address, an
+    /// address of zero is always taken as `+0`.
+    ///
+    /// # Arguments
+    /// * `instr` - The instruction to run.
+    ///
+    /// # Returns
+    /// * [`Ok(())`] - The instruction is run.
+    /// * [`Err(ErrorCode)`] - The instruction failed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.mem[1000] = FullWord::from_i64(42).0;
+    ///
+    /// mix.execute(&Instruction::new(1000, 5, 0, Opcode::LdA)).unwrap();
+    /// assert_eq!(mix.r_a.to_i64().0, 42);
+    /// assert_eq!(mix.pc, 0);
+    /// ```
+    pub fn execute(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
+        let orig_pc = self.pc;
+        // `pc` may be anything here, as nothing is fetched.
+        self.pc = self.pc.wrapping_add(1);
+        if self.pc_wrap && self.pc as usize == Mem::SIZE {
+            self.pc = 0;
+        }
+        self.jumped = false;
+        self.instr_negative = instr.addr < 0;
+        self.instr_pc = orig_pc;
+        let result = self.helper_dispatch(instr);
+        if !self.jumped {
+            self.pc = orig_pc;
+        }
+        result
+    }
+
+    /// Halt the machine.
+    pub fn halt(&mut self) {
+        self.halted = true;
+    }
+
+    /// Turn the overflow toggle on.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.set_overflow();
+    /// assert!(mix.overflow);
+    /// mix.clear_overflow();
+    /// assert!(!mix.overflow);
+    /// ```
+    pub fn set_overflow(&mut self) {
+        self.overflow = true;
+    }
+
+    /// Turn the overflow toggle off.
+    pub fn clear_overflow(&mut self) {
+        self.overflow = false;
+    }
+
+    /// Check if the machine is running, i.e. not halted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// assert!(!mix.is_running());
+    /// mix.restart();
+    /// assert!(mix.is_running());
+    /// ```
+    pub const fn is_running(&self) -> bool {
+        !self.halted
+    }
+
+    /// Get a snapshot of registers and flags.
+    pub fn registers(&self) -> Registers {
+        Registers {
+            r_a: self.r_a,
+            r_x: self.r_x,
+            r_in: self.r_in,
+            r_j: self.r_j,
+            overflow: self.overflow,
+            comp: self.comp,
+        }
+    }
+
+    /// Compare the state of two machines.
+    ///
+    /// Registers, flags, `pc`, the running state and memory are
+    /// compared. IO devices and configuration are not.
+    ///
+    /// # Arguments
+    /// * `other` - The machine to compare with.
+    ///
+    /// # Returns
+    /// * [`Vec<StateDifference>`] - The differences found, registers
+    ///   first and then memory in address order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut left = MixVM::new();
+    /// let right = MixVM::new();
+    /// left.pc = 5;
+    ///
+    /// assert_eq!(
+    ///     left.diff(&right),
+    ///     vec![StateDifference::Pc { left: 5, right: 0 }]
+    /// );
+    /// assert!(right.diff(&right).is_empty());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn diff(&self, other: &MixVM) -> Vec<StateDifference> {
+        let mut diffs = Vec::new();
+        Self::helper_diff_registers(&self.registers(), &other.registers(), &mut diffs);
+        if self.pc != other.pc {
+            diffs.push(StateDifference::Pc {
+                left: self.pc,
+                right: other.pc,
+            });
+        }
+        if self.halted != other.halted {
+            diffs.push(StateDifference::Halted {
+                left: self.halted,
+                right: other.halted,
+            });
+        }
+        for addr in 0..Mem::SIZE as u16 {
+            let (left, right) = (self.mem[addr], other.mem[addr]);
+            if left[..] != right[..] {
+                diffs.push(StateDifference::Memory { addr, left, right });
+            }
+        }
+        diffs
+    }
+
+    /// Compare registers and flags.
+    #[cfg(feature = "std")]
+    fn helper_diff_registers(
+        left: &Registers,
+        right: &Registers,
+        diffs: &mut Vec<StateDifference>,
+    ) {
+        let registers = left
+            .to_padded_words()
+            .into_iter()
+            .zip(right.to_padded_words());
+        for ((register, left), (_, right)) in registers {
+            if left[..] != right[..] {
+                diffs.push(StateDifference::Register {
+                    register,
+                    left,
+                    right,
+                });
+            }
+        }
+        if left.overflow != right.overflow {
+            diffs.push(StateDifference::Overflow {
+                left: left.overflow,
+                right: right.overflow,
+            });
+        }
+        if left.comp != right.comp {
+            diffs.push(StateDifference::Comp {
+                left: left.comp,
+                right: right.comp,
+            });
+        }
+    }
+
+    /// Get the counts of memory accesses since the last reset.
+    ///
+    /// Only accesses made by running instructions are counted.
+    /// Counts are cleared by [`MixVM::reset()`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.mem[0] = Instruction::new(2000, 5, 0, Opcode::LdA).into();
+    /// mix.mem[1] = Instruction::new(2001, 5, 0, Opcode::StA).into();
+    /// mix.reset_and_run();
+    ///
+    /// mix.step().unwrap();
+    /// mix.step().unwrap();
+    /// let expected = MemAccessStats { reads: 3, writes: 1 };
+    /// assert_eq!(mix.memory_access_stats(), expected);
+    /// ```
+    pub const fn memory_access_stats(&self) -> MemAccessStats {
+        MemAccessStats {
+            reads: self.mem.read_count(),
+            writes: self.mem.write_count(),
+        }
+    }
+
+    /// Compute a checksum of the registers, flags, `pc` and memory.
+    ///
+    /// Machines in the same state have the same checksum. This is
+    /// an FNV-1a hash, which is fast but not cryptographically secure.
+    ///
+    /// # Returns
+    /// * [`u64`] - The checksum.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix_1 = MixVM::new();
+    /// let mix_2 = MixVM::new();
+    /// assert_eq!(mix_1.checksum(), mix_2.checksum());
+    ///
+    /// mix_1.mem[100] = FullWord::from_i64(1).0;
+    /// assert_ne!(mix_1.checksum(), mix_2.checksum());
+    /// ```
+    pub fn checksum(&self) -> u64 {
+        let comp: u8 = match self.comp {
+            CompIndicator::Equal => 0,
+            CompIndicator::Less => 1,
+            CompIndicator::Greater => 2,
+            #[cfg(feature = "x-ieee754")]
+            CompIndicator::Unordered => 3,
+        };
+        let flags = [self.overflow as u8, comp];
+        let pc = self.pc.to_be_bytes();
+        let bytes = self.r_a[..]
+            .iter()
+            .chain(&self.r_x[..])
+            .chain(self.r_in.iter().flat_map(|r_i| &r_i[..]))
+            .chain(&self.r_j[..])
+            .chain(&flags)
+            .chain(&pc)
+            .chain((0..Mem::SIZE as u16).flat_map(|addr| &self.mem[addr][..]));
+        Self::helper_fnv1a(bytes)
+    }
+
+    /// Compute a hash of the words in a memory region.
+    ///
+    /// The hash changes when a word in the region changes, so the
+    /// region can be watched by polling the hash instead of comparing
+    /// every word. This is an FNV-1a hash like [`MixVM::checksum()`].
+    ///
+    /// # Arguments
+    /// * `range` - The locations to hash. Locations outside memory
+    ///   are ignored.
+    ///
+    /// # Returns
+    /// * [`u64`] - The hash.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// let hash = mix.memory_region_hash(100..200);
+    ///
+    /// mix.mem[200] = FullWord::from_i64(1).0;
+    /// assert_eq!(mix.memory_region_hash(100..200), hash);
+    /// mix.mem[150] = FullWord::from_i64(1).0;
+    /// assert_ne!(mix.memory_region_hash(100..200), hash);
+    /// ```
+    pub fn memory_region_hash(&self, range: Range<u16>) -> u64 {
+        let end = range.end.min(Mem::SIZE as u16);
+        let bytes = (range.start..end).flat_map(|addr| &self.mem[addr][..]);
+        Self::helper_fnv1a(bytes)
+    }
+
+    /// Round a float to an integer, with ties to even.
+    ///
+    /// `NaN`s and infinities are returned as is.
+    fn helper_round_ties_even(value: f32) -> f32 {
+        // Floats of this magnitude or larger are already integers.
+        const INTEGRAL_MIN: f32 = 16_777_216.0;
+
+        let magnitude = value.abs();
+        if value.is_nan() || magnitude >= INTEGRAL_MIN {
+            return value;
+        }
+        let trunc = magnitude as u32;
+        let frac = magnitude - trunc as f32;
+        let rounded = if frac > 0.5 || (frac == 0.5 && trunc % 2 == 1) {
+            trunc + 1
+        } else {
+            trunc
+        } as f32;
+        if value.is_sign_negative() {
+            -rounded
+        } else {
+            rounded
+        }
+    }
+
+    /// Compute the FNV-1a hash of bytes.
+    fn helper_fnv1a<'a>(bytes: impl Iterator<Item = &'a u8>) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xCBF29CE484222325;
+        const FNV_PRIME: u64 = 0x100000001B3;
+
+        bytes.fold(FNV_OFFSET_BASIS, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+        })
+    }
+
+    /// Read the raw word at a memory location.
+    ///
+    /// # Arguments
+    /// * `addr` - The location to read.
+    ///
+    /// # Returns
+    /// * [`Some(FullWord)`] - The word at `addr`.
+    /// * [`None`] - `addr` is out of range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.mem[100] = FullWord::from_i64(-42).0;
+    ///
+    /// assert_eq!(mix.word_at(100), Some(FullWord::from_i64(-42).0));
+    /// assert_eq!(mix.word_at(4000), None);
+    /// ```
+    pub fn word_at(&self, addr: u16) -> Option<FullWord> {
+        if !Mem::in_bounds(addr) {
+            return None;
+        }
+        Some(self.mem[addr])
+    }
+
+    /// Read the raw word to be fetched at `pc`, without decoding it.
+    ///
+    /// # Returns
+    /// * [`Some(FullWord)`] - The word at `pc`.
+    /// * [`None`] - `pc` is out of range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.mem[10] = FullWord::from_bytes([0, 0, 0, 0, 0, 255]);
+    /// mix.pc = 10;
+    ///
+    /// assert_eq!(mix.current_word(), Some(FullWord::from_bytes([0, 0, 0, 0, 0, 255])));
+    /// ```
+    pub fn current_word(&self) -> Option<FullWord> {
+        self.word_at(self.pc)
+    }
+
+    /// Read a memory location as a signed integer.
+    ///
+    /// # Arguments
+    /// * `addr` - The location to read.
+    ///
+    /// # Returns
+    /// * [`Some(i64)`] - The value of the word.
+    /// * [`None`] - `addr` is out of range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.mem[100] = FullWord::from_i64(-42).0;
+    ///
+    /// assert_eq!(mix.peek_i64(100), Some(-42));
+    /// assert_eq!(mix.peek_i64(4000), None);
+    /// ```
+    pub fn peek_i64(&self, addr: u16) -> Option<i64> {
+        if !Mem::in_bounds(addr) {
+            return None;
+        }
+        Some(self.mem[addr].to_i64().0)
+    }
+
+    /// Read a field of a memory location as a signed integer.
+    ///
+    /// The field is loaded in the same way as `LDA`, so the value is
+    /// positive unless the sign is included in the field.
+    ///
+    /// # Arguments
+    /// * `addr` - The location to read.
+    /// * `field` - The field `(L:R)`, encoded as `8 * L + R`.
+    ///
+    /// # Returns
+    /// * [`Ok(i64)`] - The value of the field.
+    /// * [`Err(ErrorCode::InvalidAddress)`] - `addr` is out of range.
+    /// * [`Err(ErrorCode::InvalidField)`] - `field` is not a valid field.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.mem[100] = FullWord::from_bytes([1, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(mix.read_field(100, 5), Ok(-0x0102030405));
+    /// assert_eq!(mix.read_field(100, 8 * 4 + 5), Ok(0x0405));
+    /// assert_eq!(mix.read_field(100, 8 * 5 + 4), Err(ErrorCode::InvalidField));
+    /// ```
+    pub fn read_field(&self, addr: u16, field: u8) -> Result<i64, ErrorCode> {
+        self.helper_check_field(field)?;
+        let word = self.word_at(addr).ok_or(ErrorCode::InvalidAddress)?;
+        Ok(word.extract_field(field).to_i64().0)
+    }
+
+    /// Write a signed integer to a memory location.
+    ///
+    /// # Arguments
+    /// * `addr` - The location to write.
+    /// * `value` - The value to write.
+    ///
+    /// # Returns
+    /// * [`Ok(())`] - The value is written.
+    /// * [`Err(ErrorCode::InvalidAddress)`] - `addr` is out of range.
+    /// * [`Err(ErrorCode::Overflow)`] - `value` does not fit in a word.
+    ///   The memory is left untouched.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    ///
+    /// mix.poke_i64(100, -42).unwrap();
+    /// assert_eq!(mix.mem[100][..], [1, 0, 0, 0, 0, 42]);
+    /// ```
+    pub fn poke_i64(&mut self, addr: u16, value: i64) -> Result<(), ErrorCode> {
+        if !Mem::in_bounds(addr) {
+            return Err(ErrorCode::InvalidAddress);
+        }
+        let (word, overflow) = FullWord::from_i64(value);
+        if overflow {
+            return Err(ErrorCode::Overflow);
+        }
+        self.mem[addr] = word;
+        Ok(())
+    }
+
+    /// Encode an instruction and write it to a memory location.
+    ///
+    /// # Arguments
+    /// * `addr` - The location to write.
+    /// * `instr` - The instruction to write.
+    ///
+    /// # Returns
+    /// * [`Ok(())`] - The instruction is written.
+    /// * [`Err(ErrorCode::InvalidAddress)`] - `addr` is out of range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    ///
+    /// mix.poke_instruction(100, Instruction::new(2000, 5, 1, Opcode::LdA))
+    ///     .unwrap();
+    /// assert_eq!(mix.mem[100][..], [0, 0x07, 0xD0, 1, 5, 8]);
+    /// ```
+    pub fn poke_instruction(&mut self, addr: u16, instr: Instruction) -> Result<(), ErrorCode> {
+        if !Mem::in_bounds(addr) {
+            return Err(ErrorCode::InvalidAddress);
+        }
+        self.mem[addr] = instr.into();
+        Ok(())
+    }
+
+    /// Check the instructions in a range of memory before running them.
+    ///
+    /// Each word in `range` is decoded, and a warning is reported for
+    /// any word that:
+    /// * is not a valid instruction;
+    /// * has an `(L:R)` field out of range, for instructions taking one;
+    /// * jumps outside memory without indexing.
+    ///
+    /// Since data words cannot be told apart from instructions, the
+    /// range should only cover code.
+    ///
+    /// # Arguments
+    /// * `range` - The locations to check.
+    ///
+    /// # Returns
+    /// * [`Vec<ProgramWarning>`] - The problems found, in ascending order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.mem[0] = Instruction::new(2000, 5, 0, Opcode::LdA).into();
+    /// mix.mem[1] = Instruction::new(2000, 7, 0, Opcode::StA).into();
+    ///
+    /// let warnings = mix.validate_program(0..2);
+    /// assert_eq!(
+    ///     warnings,
+    ///     [ProgramWarning {
+    ///         addr: 1,
+    ///         error: ErrorCode::InvalidField
+    ///     }]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn validate_program(&self, range: Range<u16>) -> Vec<ProgramWarning> {
+        let end = range.end.min(Mem::SIZE as u16);
+        let mut warnings = Vec::new();
+        for addr in range.start..end {
+            let error = match Instruction::try_from(self.mem[addr]) {
+                Err(_) => Some(ErrorCode::IllegalInstruction),
+                Ok(instr) => self.helper_validate_instr(&instr).err(),
+            };
+            if let Some(error) = error {
+                warnings.push(ProgramWarning { addr, error });
+            }
+        }
+        warnings
+    }
+
+    /// Format the registers, flags and `pc` into a readable block,
+    /// one item per line.
+    ///
+    /// # Returns
+    /// * [`String`] - The formatted dump.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.reset();
+    /// mix.r_a = FullWord::from_i64(-1).0;
+    ///
+    /// let dump = mix.dump_state();
+    /// assert!(dump.contains("rA:  - 000 000 000 000 001"));
+    /// assert!(dump.contains("PC:  0000"));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn dump_state(&self) -> String {
+        use core::fmt::Write;
+
+        let mut dump = String::new();
+        // Writing to a `String` never fails.
+        let _ = writeln!(dump, "rA:  {}", self.r_a);
+        let _ = writeln!(dump, "rX:  {}", self.r_x);
+        for (i, r_i) in self.r_in.iter().enumerate().skip(1) {
+            let _ = writeln!(dump, "rI{}: {}", i, r_i);
+        }
+        let _ = writeln!(dump, "rJ:  {}", self.r_j);
+        let _ = writeln!(dump, "OV:  {}", if self.overflow { "ON" } else { "OFF" });
+        let _ = writeln!(dump, "CI:  {:?}", self.comp);
+        let _ = writeln!(dump, "PC:  {:04}", self.pc);
+        dump
+    }
+
+    /// Enable recording of executed steps to be reverted.
+    ///
+    /// At most `max` steps are kept. Older steps are discarded
+    /// first. Recording is disabled if `max` is `0`.
+    ///
+    /// # Arguments
+    /// * `max` - The maximum count of steps to keep.
+    #[cfg(feature = "std")]
+    pub fn enable_history(&mut self, max: usize) {
+        self.history_max = max;
+        while self.history.len() > max {
+            self.history.pop_front();
+        }
+    }
+
+    /// Set the host function handling `TRAP`.
+    ///
+    /// `TRAP` is `Special` with field [`MixVM::TRAP_FIELD`]. Without a
+    /// handler, it fails with [`ErrorCode::InvalidField`]. The handler
+    /// may modify the machine freely, and its error fails the
+    /// instruction. Execution resumes after `TRAP` unless the handler
+    /// changes `pc` or halts the machine.
+    ///
+    /// # Arguments
+    /// * `handler` - The handler to call on `TRAP`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.set_trap_handler(Box::new(|mix, m| {
+    ///     mix.r_a = FullWord::from_i64(m as i64 * 2).0;
+    ///     Ok(())
+    /// }));
+    ///
+    /// mix.execute(&Instruction::new(21, MixVM::TRAP_FIELD, 0, Opcode::Special))
+    ///     .unwrap();
+    /// assert_eq!(mix.r_a.to_i64().0, 42);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn set_trap_handler(&mut self, handler: Box<TrapHandler>) {
+        self.trap_handler = Some(handler);
+    }
+
+    /// Set the hook called after each successful [`MixVM::step()`].
+    ///
+    /// Instructions run by [`MixVM::execute()`] are not traced.
+    ///
+    /// # Arguments
+    /// * `hook` - The hook to call, replacing any previous one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    /// use mixture::*;
+    ///
+    /// let count = Rc::new(Cell::new(0));
+    /// let count_hook = count.clone();
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.set_trace_hook(Box::new(move |_, _, _| count_hook.set(count_hook.get() + 1)));
+    /// mix.reset_and_run();
+    /// mix.step().unwrap();
+    /// mix.step().unwrap();
+    /// assert_eq!(count.get(), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn set_trace_hook(&mut self, hook: Box<TraceHook>) {
+        self.trace_hook = Some(hook);
+    }
+
+    /// Set the hook called when the machine executes `HLT`.
+    ///
+    /// The hook is called after IO devices are flushed and
+    /// [`MixVM::halt_reason`] is set, so it sees the final state of
+    /// the machine. It is not called when the machine halts on an
+    /// error, except if flushing IO devices fails on `HLT`. The hook
+    /// then sees [`HaltReason::Error`] with the flush error.
+    ///
+    /// # Arguments
+    /// * `hook` - The hook to call, replacing any previous one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    /// use mixture::*;
+    ///
+    /// let halted_at = Rc::new(Cell::new(0));
+    /// let halted_at_hook = halted_at.clone();
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.mem[3] = Instruction::new(0, 2, 0, Opcode::Special).into();
+    /// mix.set_halt_hook(Box::new(move |mix| halted_at_hook.set(mix.pc)));
+    /// mix.reset_and_run();
+    /// assert_eq!(mix.run_until_halt().unwrap().stop, StopReason::Halted);
+    /// assert_eq!(halted_at.get(), 4);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn set_halt_hook(&mut self, hook: Box<HaltHook>) {
+        self.halt_hook = Some(hook);
+    }
+
+    /// Write a line to `writer` for each instruction run.
+    ///
+    /// Each line holds the location and the instruction, followed by
+    /// the registers and flags changed by it, e.g.
+    /// `0000 JMP 1000,0(0) rJ=+ 000 001`. Instructions are named by
+    /// [`Opcode::mnemonic()`], or by their opcode number if it gives
+    /// none. The comparison indicator is written as `L`, `E`, `G` or
+    /// `U` for unordered. This installs a trace hook with
+    /// [`MixVM::set_trace_hook()`]. Errors from `writer` are ignored.
+    ///
+    /// # Arguments
+    /// * `writer` - The writer to log to.
+    #[cfg(feature = "std")]
+    pub fn trace_to_writer<W: Write + 'static>(&mut self, mut writer: W) {
+        let mut last = self.registers();
+        self.set_trace_hook(Box::new(move |mix, pc, instr| {
+            let regs = mix.registers();
+            let mut line = match instr.opcode.mnemonic(instr.field) {
+                Some(mnemonic) => format!("{:04} {}", pc, mnemonic),
+                None => format!("{:04} {:02}", pc, instr.opcode as u8),
+            };
+            line += &format!(" {},{}({})", instr.addr, instr.index, instr.field);
+            if regs.r_a != last.r_a {
+                line += &format!(" rA={}", regs.r_a);
+            }
+            if regs.r_x != last.r_x {
+                line += &format!(" rX={}", regs.r_x);
+            }
+            for i in 1..=6 {
+                if regs.r_in[i] != last.r_in[i] {
+                    line += &format!(" rI{}={}", i, regs.r_in[i]);
+                }
+            }
+            if regs.r_j != last.r_j {
+                line += &format!(" rJ={}", regs.r_j);
+            }
+            if regs.overflow != last.overflow {
+                line += &format!(" OV={}", regs.overflow);
+            }
+            if regs.comp != last.comp {
+                let comp = match regs.comp {
+                    CompIndicator::Less => "L",
+                    CompIndicator::Equal => "E",
+                    CompIndicator::Greater => "G",
+                    #[cfg(feature = "x-ieee754")]
+                    CompIndicator::Unordered => "U",
+                };
+                line += &format!(" CI={}", comp);
+            }
+            let _ = writeln!(writer, "{}", line);
+            last = regs;
+        }));
+    }
+
+    /// Turn the machine into an [`EventRecorder`] logging each
+    /// instruction run.
+    ///
+    /// This installs a trace hook with [`MixVM::trace_to_writer()`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.mem[0] = Instruction::new(42, 2, 0, Opcode::ModifyA).into();
+    /// mix.mem[1] = Instruction::new(0, 2, 0, Opcode::Special).into();
+    /// mix.reset_and_run();
+    ///
+    /// let mut recorder = mix.into_event_recorder();
+    /// recorder.machine_mut().run_until_halt().unwrap();
+    /// assert_eq!(
+    ///     recorder.log(),
+    ///     "0000 ENTA 42,0(2) rA=+ 000 000 000 000 042\n0001 HLT 0,0(2)\n"
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn into_event_recorder(mut self) -> EventRecorder {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        self.trace_to_writer(SharedLog(log.clone()));
+        EventRecorder { mix: self, log }
+    }
+
+    /// Revert the last recorded step of the machine.
+    ///
+    /// Registers, flags, `pc`, the running state and memory are
+    /// restored. Side effects on IO devices are not reverted.
+    ///
+    /// # Returns
+    /// * [`Ok(())`] - The step is reverted.
+    /// * [`Err(ErrorCode::NoHistory)`] - No step is recorded.
+    #[cfg(feature = "std")]
+    pub fn step_back(&mut self) -> Result<(), ErrorCode> {
+        let record = self.history.pop_back().ok_or(ErrorCode::NoHistory)?;
+        self.r_a = record.r_a;
+        self.r_x = record.r_x;
+        self.r_in = record.r_in;
+        self.r_j = record.r_j;
+        self.overflow = record.overflow;
+        self.comp = record.comp;
+        self.pc = record.pc;
+        self.halted = record.halted;
+        // Only running machines can step.
+        self.halt_reason = None;
+        self.fault_pc = None;
+        for ((addr, word), &initialized) in record.mem_cells().zip(&record.mem_initialized) {
+            self.mem[addr] = word;
+            self.mem.set_initialized(addr, initialized);
+        }
+        Ok(())
+    }
+
+    /// Get a reference to an installed IO device of a concrete type.
+    ///
+    /// # Arguments
+    /// * `unit` - The unit of the device.
+    ///
+    /// # Returns
+    /// * [`Some(&T)`] - The device of type `T` installed at `unit`.
+    /// * [`None`] - No device is installed at `unit`, or it is not a `T`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.io_devices[18] = Some(Box::new(PrinterDevice::new()));
+    ///
+    /// assert!(mix.device_ref::<PrinterDevice>(18).is_some());
+    /// assert!(mix.device_ref::<TapeDevice>(18).is_none());
+    /// ```
+    #[cfg(feature = "io")]
+    pub fn device_ref<T: IODevice>(&self, unit: usize) -> Option<&T> {
+        let dev: &dyn core::any::Any = self.io_devices.get(unit)?.as_deref()?;
+        dev.downcast_ref::<T>()
+    }
+
+    /// Install the standard MIX devices, replacing existing ones.
+    ///
+    /// The units are:
+    /// * `0` to `7` - [`TapeDevice`]s.
+    /// * `8` to `15` - [`DiskDevice`]s.
+    /// * `16` - A [`CardReaderDevice`].
+    /// * `17` - A [`CardPunchDevice`].
+    /// * `18` - A [`PrinterDevice`].
+    /// * `19` - A [`TypewriterDevice`].
+    /// * `20` - A [`PaperTapeDevice`].
+    ///
+    /// # Arguments
+    /// * `config` - The initial content and stream backings of the
+    ///   devices.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.install_standard_devices(StandardDevicesConfig::default());
+    ///
+    /// assert!(mix.device_ref::<PrinterDevice>(18).is_some());
+    /// ```
+    #[cfg(feature = "io")]
+    pub fn install_standard_devices(&mut self, config: StandardDevicesConfig) {
+        use std::boxed::Box;
+
+        for (unit, words) in config.tapes.into_iter().enumerate() {
+            self.io_devices[unit] = Some(Box::new(TapeDevice::from_words(words)));
+        }
+        for (unit, words) in config.disks.into_iter().enumerate() {
+            let disk = DiskDevice::from_words(words).with_capacity(config.disk_capacity);
+            self.io_devices[8 + unit] = Some(Box::new(disk));
+        }
+        let mut reader = CardReaderDevice::new(config.cards);
+        if let Some(stream) = config.cards_reader {
+            reader = reader.with_reader(stream);
+        }
+        self.io_devices[16] = Some(Box::new(reader));
+        let mut punch = CardPunchDevice::new();
+        if let Some(stream) = config.punch_writer {
+            punch = punch.with_writer(stream);
+        }
+        self.io_devices[17] = Some(Box::new(punch));
+        let mut printer = PrinterDevice::new();
+        if let Some(stream) = config.printer_writer {
+            printer = printer.with_writer(stream);
+        }
+        self.io_devices[18] = Some(Box::new(printer));
+        let mut typewriter = TypewriterDevice::new(config.typewriter);
+        if let Some(stream) = config.typewriter_reader {
+            typewriter = typewriter.with_reader(stream);
+        }
+        if let Some(stream) = config.typewriter_writer {
+            typewriter = typewriter.with_writer(stream);
+        }
+        self.io_devices[19] = Some(Box::new(typewriter));
+        self.io_devices[20] = Some(Box::new(PaperTapeDevice::from_words(config.paper_tape)));
     }
 
-    /// Run the next instruction of the machine.
+    /// Flush buffered output of all installed IO devices.
+    ///
+    /// This is done automatically when the machine executes `HLT`.
+    /// All devices are flushed even if some of them fail.
     ///
     /// # Returns
-    /// * [`Ok(())`] - The machine successfully completed its operation.
-    /// * [`Err(ErrorCode)`] - The machine encountered an error and is now halted.
-    pub fn step(&mut self) -> Result<(), ErrorCode> {
-        if self.halted {
-            return Err(ErrorCode::Halted);
+    /// * [`Ok(())`] - All devices are flushed.
+    /// * [`Err(ErrorCode::IOError)`] - Some device failed to flush.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mixture::*;
+    ///
+    /// let mut mix = MixVM::new();
+    /// mix.io_devices[18] = Some(Box::new(PrinterDevice::new()));
+    ///
+    /// mix.flush_io().unwrap();
+    /// ```
+    #[cfg(feature = "io")]
+    pub fn flush_io(&mut self) -> Result<(), ErrorCode> {
+        let mut result = Ok(());
+        for dev in self.io_devices.iter_mut().flatten() {
+            if dev.flush().is_err() {
+                result = Err(ErrorCode::IOError);
+            }
         }
+        result
+    }
 
-        // Fetch the instruction.
-        let instr: Instruction = self.mem[self.pc].try_into().map_err(|_| {
-            self.halt();
-            ErrorCode::IllegalInstruction
-        })?;
-
-        self.pc += 1;
-
-        // Run the instruction.
-        match instr.opcode {
-            Opcode::Nop => self.handle_instr_nop(&instr),
-
-            Opcode::Add => self.handle_instr_add_sub(&instr),
-            Opcode::Sub => self.handle_instr_add_sub(&instr),
-            Opcode::Mul => self.handle_instr_mul(&instr),
-            Opcode::Div => self.handle_instr_div(&instr),
-
-            Opcode::Special => self.handle_instr_special(&instr),
-            Opcode::Shift => self.handle_instr_shift(&instr),
-            Opcode::Move => self.handle_instr_move(&instr),
-
-            Opcode::LdA => self.handle_instr_load_6b(&instr),
-            Opcode::Ld1 => self.handle_instr_load_3b(&instr),
-            Opcode::Ld2 => self.handle_instr_load_3b(&instr),
-            Opcode::Ld3 => self.handle_instr_load_3b(&instr),
-            Opcode::Ld4 => self.handle_instr_load_3b(&instr),
-            Opcode::Ld5 => self.handle_instr_load_3b(&instr),
-            Opcode::Ld6 => self.handle_instr_load_3b(&instr),
-            Opcode::LdX => self.handle_instr_load_6b(&instr),
+    /// Save current state before a step, if needed.
+    #[cfg(feature = "std")]
+    fn helper_make_record(&self) -> Option<StepRecord> {
+        if self.history_max == 0 && !self.recording {
+            return None;
+        }
+        Some(self.helper_snapshot())
+    }
 
-            Opcode::LdAN => self.handle_instr_load_neg_6b(&instr),
-            Opcode::Ld1N => self.handle_instr_load_neg_3b(&instr),
-            Opcode::Ld2N => self.handle_instr_load_neg_3b(&instr),
-            Opcode::Ld3N => self.handle_instr_load_neg_3b(&instr),
-            Opcode::Ld4N => self.handle_instr_load_neg_3b(&instr),
-            Opcode::Ld5N => self.handle_instr_load_neg_3b(&instr),
-            Opcode::Ld6N => self.handle_instr_load_neg_3b(&instr),
-            Opcode::LdXN => self.handle_instr_load_neg_6b(&instr),
+    /// Save current state before a step.
+    #[cfg(feature = "std")]
+    fn helper_snapshot(&self) -> StepRecord {
+        let mem_range = self
+            .word_at(self.pc)
+            .and_then(|word| Instruction::try_from(word).ok())
+            .map(|instr| self.helper_get_dirty_range(&instr))
+            .unwrap_or(0..0);
+        StepRecord {
+            r_a: self.r_a,
+            r_x: self.r_x,
+            r_in: self.r_in,
+            r_j: self.r_j,
+            overflow: self.overflow,
+            comp: self.comp,
+            pc: self.pc,
+            halted: self.halted,
+            mem_start: mem_range.start,
+            mem_words: mem_range
+                .clone()
+                .map(|addr| self.mem[(addr % Mem::SIZE) as u16])
+                .collect(),
+            mem_initialized: mem_range
+                .map(|addr| self.mem.is_initialized((addr % Mem::SIZE) as u16))
+                .collect(),
+        }
+    }
 
-            Opcode::StA => self.handle_instr_store_6b(&instr),
-            Opcode::St1 => self.handle_instr_store_3b(&instr),
-            Opcode::St2 => self.handle_instr_store_3b(&instr),
-            Opcode::St3 => self.handle_instr_store_3b(&instr),
-            Opcode::St4 => self.handle_instr_store_3b(&instr),
-            Opcode::St5 => self.handle_instr_store_3b(&instr),
-            Opcode::St6 => self.handle_instr_store_3b(&instr),
-            Opcode::StX => self.handle_instr_store_6b(&instr),
-            Opcode::StJ => self.handle_instr_store_j(&instr),
-            Opcode::StZ => self.handle_instr_store_zero(&instr),
+    /// Use the state saved before a step for events and history.
+    #[cfg(feature = "std")]
+    fn helper_commit_record(&mut self, record: StepRecord) {
+        if self.recording {
+            let registers = record
+                .registers()
+                .to_padded_words()
+                .into_iter()
+                .zip(self.registers().to_padded_words());
+            for ((register, old), (_, new)) in registers {
+                if old[..] != new[..] {
+                    self.events.push(MachineEvent::RegisterWrite {
+                        register,
+                        value: new,
+                    });
+                }
+            }
+            for (addr, old) in record.mem_cells() {
+                if old[..] != self.mem[addr][..] {
+                    self.events.push(MachineEvent::MemoryWrite {
+                        addr,
+                        value: self.mem[addr],
+                    });
+                }
+            }
+            if !record.overflow && self.overflow {
+                self.events.push(MachineEvent::OverflowSet);
+            }
+        }
+        if self.history_max > 0 {
+            if self.history.len() >= self.history_max {
+                self.history.pop_front();
+            }
+            self.history.push_back(record);
+        }
+    }
 
-            Opcode::Jbus => self.handle_instr_jbus_jred(&instr),
-            Opcode::Ioc => self.handle_instr_ioc(&instr),
-            Opcode::In => self.handle_instr_in_out(&instr),
-            Opcode::Out => self.handle_instr_in_out(&instr),
-            Opcode::Jred => self.handle_instr_jbus_jred(&instr),
-            Opcode::Jmp => self.handle_instr_jmp(&instr),
+    /// Record an event, if enabled.
+    #[cfg(feature = "std")]
+    fn helper_push_event(&mut self, event: MachineEvent) {
+        if self.recording {
+            self.events.push(event);
+        }
+    }
 
-            Opcode::JA => self.handle_instr_jmp_reg_6b(&instr),
-            Opcode::J1 => self.handle_instr_jmp_reg_3b(&instr),
-            Opcode::J2 => self.handle_instr_jmp_reg_3b(&instr),
-            Opcode::J3 => self.handle_instr_jmp_reg_3b(&instr),
-            Opcode::J4 => self.handle_instr_jmp_reg_3b(&instr),
-            Opcode::J5 => self.handle_instr_jmp_reg_3b(&instr),
-            Opcode::J6 => self.handle_instr_jmp_reg_3b(&instr),
-            Opcode::JX => self.handle_instr_jmp_reg_6b(&instr),
+    /// Record a warning, if enabled.
+    #[cfg(feature = "std")]
+    fn helper_push_warning(&mut self, warning: RuntimeWarning) {
+        if self.collect_warnings {
+            self.warnings.push(warning);
+        }
+    }
 
-            Opcode::ModifyA => self.handle_instr_modify_6b(&instr),
-            Opcode::Modify1 => self.handle_instr_modify_3b(&instr),
-            Opcode::Modify2 => self.handle_instr_modify_3b(&instr),
-            Opcode::Modify3 => self.handle_instr_modify_3b(&instr),
-            Opcode::Modify4 => self.handle_instr_modify_3b(&instr),
-            Opcode::Modify5 => self.handle_instr_modify_3b(&instr),
-            Opcode::Modify6 => self.handle_instr_modify_3b(&instr),
-            Opcode::ModifyX => self.handle_instr_modify_6b(&instr),
+    /// Record a warning if a value loaded into `rIi` has bytes
+    /// dropped.
+    #[cfg(feature = "std")]
+    fn helper_warn_index_truncated(&mut self, index: u8, value: &FullWord) {
+        if value[1..=3].iter().any(|&byte| byte != 0) {
+            self.helper_push_warning(RuntimeWarning::IndexTruncated {
+                pc: self.instr_pc,
+                index,
+            });
+        }
+    }
 
-            Opcode::CmpA => self.handle_instr_cmp_6b(&instr),
-            Opcode::Cmp1 => self.handle_instr_cmp_3b(&instr),
-            Opcode::Cmp2 => self.handle_instr_cmp_3b(&instr),
-            Opcode::Cmp3 => self.handle_instr_cmp_3b(&instr),
-            Opcode::Cmp4 => self.handle_instr_cmp_3b(&instr),
-            Opcode::Cmp5 => self.handle_instr_cmp_3b(&instr),
-            Opcode::Cmp6 => self.handle_instr_cmp_3b(&instr),
-            Opcode::CmpX => self.handle_instr_cmp_6b(&instr),
+    /// Get the range of memory cells possibly written by an instruction.
+    ///
+    /// The range may go past the end of memory if targets wrap around.
+    #[cfg(feature = "std")]
+    fn helper_get_dirty_range(&self, instr: &Instruction) -> Range<usize> {
+        let eff_addr = self.helper_get_eff_addr(instr.addr, instr.index);
+        let (start, len) = match instr.opcode {
+            Opcode::StA
+            | Opcode::St1
+            | Opcode::St2
+            | Opcode::St3
+            | Opcode::St4
+            | Opcode::St5
+            | Opcode::St6
+            | Opcode::StX
+            | Opcode::StJ
+            | Opcode::StZ => (eff_addr, 1),
+            Opcode::Move => (
+                Ok(u16::from_be_bytes([self.r_in[1][1], self.r_in[1][2]])),
+                instr.field as usize,
+            ),
+            #[cfg(feature = "io")]
+            Opcode::In => (
+                eff_addr,
+                self.helper_get_io_device(instr.field as usize)
+                    .map_or(0, |dev| dev.get_block_size()),
+            ),
+            _ => return 0..0,
+        };
+        match start {
+            Ok(start) if self.address_wrap => {
+                let start = start as usize % Mem::SIZE;
+                start..start + len
+            }
+            Ok(start) => {
+                let start = (start as usize).min(Mem::SIZE);
+                start..(start + len).min(Mem::SIZE)
+            }
+            Err(_) => 0..0,
         }
-        .map_err(|err| {
-            self.halt();
-            err
-        })?;
+    }
 
+    /// Check that a field `F = 8 * L + R` satisfies `L <= R <= 5`.
+    fn helper_check_field(&self, field: u8) -> Result<(), ErrorCode> {
+        let range = field.to_range_inclusive();
+        if range.start() > range.end() || *range.end() > 5 {
+            return Err(ErrorCode::InvalidField);
+        }
         Ok(())
     }
 
-    /// Halt the machine.
-    pub fn halt(&mut self) {
-        self.halted = true;
+    /// Statically check a decoded instruction.
+    #[cfg(feature = "std")]
+    fn helper_validate_instr(&self, instr: &Instruction) -> Result<(), ErrorCode> {
+        let is_float = cfg!(feature = "x-ieee754") && instr.field == 7;
+        match instr.opcode {
+            // Loads and stores.
+            Opcode::LdA
+            | Opcode::Ld1
+            | Opcode::Ld2
+            | Opcode::Ld3
+            | Opcode::Ld4
+            | Opcode::Ld5
+            | Opcode::Ld6
+            | Opcode::LdX
+            | Opcode::LdAN
+            | Opcode::Ld1N
+            | Opcode::Ld2N
+            | Opcode::Ld3N
+            | Opcode::Ld4N
+            | Opcode::Ld5N
+            | Opcode::Ld6N
+            | Opcode::LdXN
+            | Opcode::StA
+            | Opcode::St1
+            | Opcode::St2
+            | Opcode::St3
+            | Opcode::St4
+            | Opcode::St5
+            | Opcode::St6
+            | Opcode::StX
+            | Opcode::StJ
+            | Opcode::StZ => self.helper_check_field(instr.field),
+            // Arithmetic and comparisons.
+            Opcode::Add
+            | Opcode::Sub
+            | Opcode::Mul
+            | Opcode::Div
+            | Opcode::CmpA
+            | Opcode::Cmp1
+            | Opcode::Cmp2
+            | Opcode::Cmp3
+            | Opcode::Cmp4
+            | Opcode::Cmp5
+            | Opcode::Cmp6
+            | Opcode::CmpX
+                if !is_float =>
+            {
+                self.helper_check_field(instr.field)
+            }
+            // Jumps.
+            opcode
+                if opcode.is_jump()
+                    && instr.index == 0
+                    && !(0..Mem::SIZE as i16).contains(&instr.addr) =>
+            {
+                Err(ErrorCode::InvalidAddress)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Read a memory location, honoring [`MixVM::strict_uninit`].
+    fn helper_read_mem(&mut self, addr: u16) -> Result<FullWord, ErrorCode> {
+        if !Mem::in_bounds(addr) {
+            return Err(ErrorCode::InvalidAddress);
+        }
+        if self.strict_uninit && !self.mem.is_initialized(addr) {
+            return Err(ErrorCode::UninitializedRead);
+        }
+        #[cfg(feature = "std")]
+        if !self.mem.is_initialized(addr) {
+            self.helper_push_warning(RuntimeWarning::UninitializedRead {
+                pc: self.instr_pc,
+                addr,
+            });
+        }
+        self.mem.count_reads(1);
+        Ok(self.mem[addr])
     }
 
     /// Get indexed address.
@@ -267,6 +2465,15 @@ impl MixVM {
             .map_err(|_| ErrorCode::InvalidAddress)
     }
 
+    /// Get indexed address of a memory cell.
+    fn helper_get_mem_addr(&self, addr: i16, index: u8) -> Result<u16, ErrorCode> {
+        let addr = self.helper_get_eff_addr(addr, index)?;
+        if !Mem::in_bounds(addr) {
+            return Err(ErrorCode::InvalidAddress);
+        }
+        Ok(addr)
+    }
+
     /// Get indexed address. May panic or return negative value.
     fn helper_get_eff_addr_unchecked(&self, addr: i16, index: u8) -> i16 {
         let reg = self.r_in[index as usize];
@@ -274,6 +2481,40 @@ impl MixVM {
         reg_val as i16 + addr
     }
 
+    /// Call the trace hook after a step.
+    #[cfg(feature = "std")]
+    fn helper_trace(&mut self, pc: u16, instr: &Instruction) {
+        if let Some(mut hook) = self.trace_hook.take() {
+            hook(self, pc, instr);
+            self.trace_hook = Some(hook);
+        }
+    }
+
+    /// Call the trap handler for `TRAP`.
+    fn helper_trap(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
+        #[cfg(feature = "std")]
+        {
+            if !(0..=6).contains(&instr.index) {
+                return Err(ErrorCode::InvalidIndex);
+            }
+            let m = (self.r_in[instr.index as usize].to_i64().0 + instr.addr as i64)
+                .try_into()
+                .map_err(|_| ErrorCode::InvalidAddress)?;
+            let mut handler = self.trap_handler.take().ok_or(ErrorCode::InvalidField)?;
+            let result = handler(self, m);
+            // The handler may have installed another one.
+            if self.trap_handler.is_none() {
+                self.trap_handler = Some(handler);
+            }
+            result
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            let _ = instr;
+            Err(ErrorCode::InvalidField)
+        }
+    }
+
     /// Do actual jump.
     fn helper_do_jump(&mut self, location: u16, save_r_j: bool) {
         if save_r_j {
@@ -281,7 +2522,20 @@ impl MixVM {
             self.r_j[1..=2].copy_from_slice(&pc);
         }
         // Do jump.
+        #[cfg(feature = "std")]
+        self.helper_push_event(MachineEvent::Jump {
+            from: self.instr_pc,
+            to: location,
+        });
+        #[cfg(feature = "std")]
+        if Mem::in_bounds(location) && Instruction::try_from(self.mem[location]).is_err() {
+            self.helper_push_warning(RuntimeWarning::JumpToData {
+                pc: self.instr_pc,
+                to: location,
+            });
+        }
         self.pc = location;
+        self.jumped = true;
     }
 
     /// Get IO device.
@@ -312,6 +2566,12 @@ impl MixVM {
         Ok(dev)
     }
 
+    /// Check if a unit is a disk, taking its block address from `rX`.
+    #[cfg(feature = "io")]
+    const fn helper_is_disk(dev_id: usize) -> bool {
+        matches!(dev_id, 8..=15)
+    }
+
     /// Handler for `NOP`.
     fn handle_instr_nop(&mut self, _: &Instruction) -> Result<(), ErrorCode> {
         // Do nothing.
@@ -320,46 +2580,32 @@ impl MixVM {
 
     /// Handler for `LDA` and `LDX`.
     fn handle_instr_load_6b(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
+        self.helper_check_field(instr.field)?;
         // Obtain everything.
-        let (field, sign_copy_needed) = instr.field.to_range_inclusive_signless();
-        let mem_cell = &self.mem[self.helper_get_eff_addr(instr.addr, instr.index)?];
+        let mem_cell = &self.helper_read_mem(self.helper_get_mem_addr(instr.addr, instr.index)?)?;
         let reg = match instr.opcode {
             Opcode::LdA => &mut self.r_a,
             Opcode::LdX => &mut self.r_x,
             _ => unreachable!(),
         };
-        // Zero reg before copying. Handle 'understood' positive sign too.
-        reg.set_all([FullWord::POS, 0, 0, 0, 0, 0]);
-        // Copy bytes shifted right.
-        for (reg_cursor, mem_cursor) in (1..=5).rev().zip(field.rev()) {
-            reg[reg_cursor] = mem_cell[mem_cursor];
-        }
-        // Copy sign byte if needed.
-        if sign_copy_needed {
-            reg[0] = mem_cell[0];
-        }
+        *reg = mem_cell.extract_field(instr.field);
         Ok(())
     }
 
     /// Handler for `LDAN` and `LDXN`.
     fn handle_instr_load_neg_6b(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
+        self.helper_check_field(instr.field)?;
         // Obtain everything.
-        let (field, sign_copy_needed) = instr.field.to_range_inclusive_signless();
-        let mem_cell = &self.mem[self.helper_get_eff_addr(instr.addr, instr.index)?];
+        let (_, sign_copy_needed) = instr.field.to_range_inclusive_signless();
+        let mem_cell = &self.helper_read_mem(self.helper_get_mem_addr(instr.addr, instr.index)?)?;
         let reg = match instr.opcode {
             Opcode::LdAN => &mut self.r_a,
             Opcode::LdXN => &mut self.r_x,
             _ => unreachable!(),
         };
-        // Zero reg before copying. Handle 'understood' negative sign.
-        reg.set_all([FullWord::POS, 0, 0, 0, 0, 0]);
-        // Copy bytes shifted right.
-        for (reg_cursor, mem_cursor) in (1..=5).rev().zip(field.rev()) {
-            reg[reg_cursor] = mem_cell[mem_cursor];
-        }
-        // Copy negated sign byte if needed.
+        *reg = mem_cell.extract_field(instr.field);
+        // Negate sign byte if copied.
         if sign_copy_needed {
-            reg[0] = mem_cell[0];
             reg.flip_sign();
         }
         Ok(())
@@ -371,9 +2617,9 @@ impl MixVM {
     /// and 5th bits of the original memory location. This prevents
     /// the said 'undefined behavior' from happening.
     fn handle_instr_load_3b(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
+        self.helper_check_field(instr.field)?;
         // Obtain everything.
-        let (field, sign_copy_needed) = instr.field.to_range_inclusive_signless();
-        let mem_cell = &self.mem[self.helper_get_eff_addr(instr.addr, instr.index)?];
+        let mem_cell = &self.helper_read_mem(self.helper_get_mem_addr(instr.addr, instr.index)?)?;
         let reg = match instr.opcode {
             Opcode::Ld1 => &mut self.r_in[1],
             Opcode::Ld2 => &mut self.r_in[2],
@@ -385,20 +2631,14 @@ impl MixVM {
         };
         // We need to care about only the 4th, 5th and the sign byte.
         // So we make a temporary word and fill back the reg only the
-        // 4th, 5th and the sign byte. Handle 'understood' positive sign.
-        let mut temp = FullWord::from_bytes([FullWord::POS, 0, 0, 0, 0, 0]);
-        // Copy bytes shifted right.
-        for (reg_cursor, mem_cursor) in (1..=5).rev().zip(field.rev()) {
-            temp[reg_cursor] = mem_cell[mem_cursor];
-        }
-        // Copy sign byte if needed.
-        if sign_copy_needed {
-            temp[0] = mem_cell[0];
-        }
+        // 4th, 5th and the sign byte.
+        let temp = mem_cell.extract_field(instr.field);
         // Fill back the reg.
         reg[0] = temp[0];
         reg[1] = temp[4];
         reg[2] = temp[5];
+        #[cfg(feature = "std")]
+        self.helper_warn_index_truncated(instr.opcode as u8 - Opcode::Ld1 as u8 + 1, &temp);
         Ok(())
     }
 
@@ -408,9 +2648,11 @@ impl MixVM {
     /// and 5th bits of the original memory location. This prevents
     /// the said 'undefined behavior' from happening.
     fn handle_instr_load_neg_3b(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
+        self.helper_check_field(instr.field)?;
         // Obtain everything.
-        let (field, sign_copy_needed) = instr.field.to_range_inclusive_signless();
-        let memory_cell = &self.mem[self.helper_get_eff_addr(instr.addr, instr.index)?];
+        let (_, sign_copy_needed) = instr.field.to_range_inclusive_signless();
+        let memory_cell =
+            &self.helper_read_mem(self.helper_get_mem_addr(instr.addr, instr.index)?)?;
         let reg = match instr.opcode {
             Opcode::Ld1N => &mut self.r_in[1],
             Opcode::Ld2N => &mut self.r_in[2],
@@ -422,27 +2664,24 @@ impl MixVM {
         };
         // We need to care about only the 4th, 5th and the sign byte.
         // So we make a temporary word and fill back the reg only the
-        // 4th, 5th and the sign byte. Handle 'understood' positive sign.
-        let mut temp = FullWord::from_bytes([FullWord::POS, 0, 0, 0, 0, 0]);
-        // Copy bytes shifted right.
-        for (reg_cursor, memory_cell_cursor) in (1..=5).rev().zip(field.rev()) {
-            temp[reg_cursor] = memory_cell[memory_cell_cursor];
-        }
-        // Copy negated sign byte if needed.
+        // 4th, 5th and the sign byte.
+        let mut temp = memory_cell.extract_field(instr.field);
+        // Negate sign byte if copied.
         if sign_copy_needed {
-            temp[0] = memory_cell[0];
             temp.flip_sign();
         }
         // Fill back the reg.
         reg[0] = temp[0];
         reg[1] = temp[4];
         reg[2] = temp[5];
+        #[cfg(feature = "std")]
+        self.helper_warn_index_truncated(instr.opcode as u8 - Opcode::Ld1N as u8 + 1, &temp);
         Ok(())
     }
 
     /// Handler for `JMP` and variants.
     fn handle_instr_jmp(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
-        let target_addr = self.helper_get_eff_addr(instr.addr, instr.index)?;
+        let target_addr = self.helper_get_mem_addr(instr.addr, instr.index)?;
         // Match jump conditions.
         let should_jump = match instr.field {
             0 | 1 => true,
@@ -517,18 +2756,49 @@ impl MixVM {
             // Making it just like NOP if we restart the
             // machine later.
             self.halted = true;
-            Ok(())
+            self.halt_reason = if self.warn_overflow_on_halt && self.overflow {
+                Some(HaltReason::HltWithOverflow)
+            } else {
+                Some(HaltReason::Hlt)
+            };
+            #[cfg(feature = "io")]
+            {
+                // Report the first device still working.
+                let busy_unit = self
+                    .io_devices
+                    .iter()
+                    .position(|dev| dev.as_ref().is_some_and(|dev| dev.is_busy() == Ok(true)));
+                if let Some(unit) = busy_unit {
+                    self.halt_reason = Some(HaltReason::PendingIO(unit as u8));
+                }
+            }
+            #[cfg(feature = "io")]
+            let flushed = self.flush_io();
+            #[cfg(not(feature = "io"))]
+            let flushed = Ok(());
+            // The machine is halted anyway, so the hook still runs.
+            if let Err(err) = flushed {
+                self.halt_reason = Some(HaltReason::Error(err));
+            }
+            #[cfg(feature = "std")]
+            if let Some(mut hook) = self.halt_hook.take() {
+                hook(self);
+                self.halt_hook = Some(hook);
+            }
+            flushed
         } else if cfg!(feature = "x-ieee754") && instr.field >= 3 && instr.field <= 8 {
             if instr.field == 3 {
                 // F32CVTF322I4B
                 let reg = &mut self.r_a;
-                let orig_value = f32::from_be_bytes([reg[2], reg[3], reg[4], reg[5]]);
+                let orig_value = Self::helper_round_ties_even(f32::from_be_bytes([
+                    reg[2], reg[3], reg[4], reg[5],
+                ]));
                 reg.set_all([FullWord::POS, 0, 0, 0, 0, 0]);
-                reg[0] = if orig_value.is_sign_positive() {
-                    FullWord::POS
+                reg.set_sign(if orig_value.is_sign_positive() {
+                    Sign::Positive
                 } else {
-                    FullWord::NEG
-                };
+                    Sign::Negative
+                });
                 if !orig_value.is_finite()
                     || orig_value > i32::MAX as f32
                     || orig_value < i32::MIN as f32
@@ -541,13 +2811,15 @@ impl MixVM {
             } else if instr.field == 4 {
                 // F32CVTF322I2B
                 let reg = &mut self.r_a;
-                let orig_value = f32::from_be_bytes([reg[2], reg[3], reg[4], reg[5]]);
+                let orig_value = Self::helper_round_ties_even(f32::from_be_bytes([
+                    reg[2], reg[3], reg[4], reg[5],
+                ]));
                 reg.set_all([FullWord::POS, 0, 0, 0, 0, 0]);
-                reg[0] = if orig_value.is_sign_positive() {
-                    FullWord::POS
+                reg.set_sign(if orig_value.is_sign_positive() {
+                    Sign::Positive
                 } else {
-                    FullWord::NEG
-                };
+                    Sign::Negative
+                });
                 if !orig_value.is_finite()
                     || orig_value > i16::MAX as f32
                     || orig_value < i16::MIN as f32
@@ -560,13 +2832,15 @@ impl MixVM {
             } else if instr.field == 5 {
                 // F32CVTF322I1B
                 let reg = &mut self.r_a;
-                let orig_value = f32::from_be_bytes([reg[2], reg[3], reg[4], reg[5]]);
+                let orig_value = Self::helper_round_ties_even(f32::from_be_bytes([
+                    reg[2], reg[3], reg[4], reg[5],
+                ]));
                 reg.set_all([FullWord::POS, 0, 0, 0, 0, 0]);
-                reg[0] = if orig_value.is_sign_positive() {
-                    FullWord::POS
+                reg.set_sign(if orig_value.is_sign_positive() {
+                    Sign::Positive
                 } else {
-                    FullWord::NEG
-                };
+                    Sign::Negative
+                });
                 if !orig_value.is_finite()
                     || orig_value > i8::MAX as f32
                     || orig_value < i8::MIN as f32
@@ -601,9 +2875,9 @@ impl MixVM {
                 }
                 Ok(())
             } else {
-                let addr = self.helper_get_eff_addr(instr.addr, instr.index)?;
+                let addr = self.helper_get_mem_addr(instr.addr, instr.index)?;
+                let mem_cell = &self.helper_read_mem(addr)?;
                 let reg = &mut self.r_a;
-                let mem_cell = &mut self.mem[addr];
                 let map_fn = match instr.field {
                     // AND
                     10 => |a: u8, b: u8| a & b,
@@ -618,6 +2892,8 @@ impl MixVM {
                 }
                 Ok(())
             }
+        } else if instr.field == Self::TRAP_FIELD {
+            self.helper_trap(instr)
         } else {
             Err(ErrorCode::InvalidField)
         }
@@ -625,33 +2901,38 @@ impl MixVM {
 
     /// Handler for `STZ`.
     fn handle_instr_store_zero(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
+        self.helper_check_field(instr.field)?;
         // Obtain everything.
-        let addr = self.helper_get_eff_addr(instr.addr, instr.index)?;
-        let field = instr.field.to_range_inclusive();
-        let mem_cell = &mut self.mem[addr];
+        let addr = self.helper_get_mem_addr(instr.addr, instr.index)?;
+        self.mem.count_writes(1);
         // Zero the memory cell.
-        for i in field {
-            if i == 0 {
-                // Deal with signs.
-                mem_cell[0] = FullWord::POS;
-            } else {
-                mem_cell[i] = 0;
-            }
-        }
+        self.mem[addr].set_field(instr.field, &FullWord::new());
         Ok(())
     }
 
     /// Handler for `MOVE`.
     fn handle_instr_move(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
         // Obtain from address.
-        let from_addr = self.helper_get_eff_addr(instr.addr, instr.index)?;
+        let from_addr = self.helper_get_mem_addr(instr.addr, instr.index)?;
         // Obtain to address.
         let to_addr = u16::from_be_bytes([self.r_in[1][1], self.r_in[1][2]]);
         let num_words = instr.field;
+        if num_words == 0 {
+            // Nothing is moved, and rI1 is left as is.
+            return Ok(());
+        }
+        // Check both spans. The target may wrap around if allowed.
+        if from_addr as usize + num_words as usize > Mem::SIZE
+            || (!self.address_wrap && to_addr as usize + num_words as usize > Mem::SIZE)
+        {
+            return Err(ErrorCode::InvalidAddress);
+        }
         // Move each word.
         for i in 0..num_words {
-            let orig_mem = self.mem[from_addr + i as u16];
-            self.mem[to_addr + i as u16].clone_from(&orig_mem);
+            let orig_mem = self.helper_read_mem(from_addr + i as u16)?;
+            let target = ((to_addr as usize + i as usize) % Mem::SIZE) as u16;
+            self.mem[target].clone_from(&orig_mem);
+            self.mem.count_writes(1);
         }
         let new_r_i1_val = self.r_in[1].to_i64().0 + num_words as i64;
         let (new_r_i1, overflow) = HalfWord::from_i64(new_r_i1_val);
@@ -664,32 +2945,25 @@ impl MixVM {
 
     /// Handler for `STA` and `STX`.
     fn handle_instr_store_6b(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
+        self.helper_check_field(instr.field)?;
         // Obtain everything.
-        let (field, sign_copy_needed) = instr.field.to_range_inclusive_signless();
-        let addr = self.helper_get_eff_addr(instr.addr, instr.index)?;
-        let mem_cell = &mut self.mem[addr];
+        let addr = self.helper_get_mem_addr(instr.addr, instr.index)?;
+        self.mem.count_writes(1);
         let reg = match instr.opcode {
             Opcode::StA => &self.r_a,
             Opcode::StX => &self.r_x,
             _ => unreachable!(),
         };
-        // Copy bytes shifted right.
-        for (reg_cursor, mem_cursor) in (1..=5).rev().zip(field.rev()) {
-            mem_cell[mem_cursor] = reg[reg_cursor];
-        }
-        if sign_copy_needed {
-            // Copy sign bit.
-            mem_cell[0] = reg[0];
-        }
+        self.mem[addr].set_field(instr.field, reg);
         Ok(())
     }
 
     /// Handler for `ST1-6`.
     fn handle_instr_store_3b(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
+        self.helper_check_field(instr.field)?;
         // Obtain everything.
-        let (field, sign_copy_needed) = instr.field.to_range_inclusive_signless();
-        let addr = self.helper_get_eff_addr(instr.addr, instr.index)?;
-        let mem_cell = &mut self.mem[addr];
+        let addr = self.helper_get_mem_addr(instr.addr, instr.index)?;
+        self.mem.count_writes(1);
         let reg = match instr.opcode {
             Opcode::St1 => &self.r_in[1],
             Opcode::St2 => &self.r_in[2],
@@ -699,34 +2973,18 @@ impl MixVM {
             Opcode::St6 => &self.r_in[6],
             _ => unreachable!(),
         };
-        let padded_reg = [reg[0], 0, 0, 0, reg[1], reg[2]];
-        // Copy bytes shifted right.
-        for (reg_cursor, mem_cursor) in (1..=5).rev().zip(field.rev()) {
-            mem_cell[mem_cursor] = padded_reg[reg_cursor];
-        }
-        if sign_copy_needed {
-            // Copy sign bit.
-            mem_cell[0] = padded_reg[0];
-        }
+        // Bytes beyond the register are zeroed.
+        self.mem[addr].set_field(instr.field, reg);
         Ok(())
     }
 
     /// Handler for `STJ`.
     fn handle_instr_store_j(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
+        self.helper_check_field(instr.field)?;
         // Obtain everything.
-        let (field, sign_copy_needed) = instr.field.to_range_inclusive_signless();
-        let addr = self.helper_get_eff_addr(instr.addr, instr.index)?;
-        let mem_cell = &mut self.mem[addr];
-        let reg = &self.r_j;
-        let padded_reg = [reg[0], 0, 0, 0, reg[1], reg[2]];
-        // Copy bytes shifted right.
-        for (reg_cursor, mem_cursor) in (1..=5).rev().zip(field.rev()) {
-            mem_cell[mem_cursor] = padded_reg[reg_cursor];
-        }
-        if sign_copy_needed {
-            // Copy sign bit.
-            mem_cell[0] = padded_reg[0];
-        }
+        let addr = self.helper_get_mem_addr(instr.addr, instr.index)?;
+        self.mem.count_writes(1);
+        self.mem[addr].set_field(instr.field, &self.r_j);
         Ok(())
     }
 
@@ -755,7 +3013,11 @@ impl MixVM {
             Ok(())
         } else if instr.field == 2 || instr.field == 3 {
             // ENTx and ENNx
-            let new_word = FullWord::from_i64(addr as i64).0;
+            let mut new_word = FullWord::from_i64(addr as i64).0;
+            if addr == 0 && self.instr_negative {
+                // The sign of the instruction is loaded if M is zero.
+                new_word.set_sign(Sign::Negative);
+            }
             // Copy new word into reg.
             reg.clone_from(&new_word);
             if instr.field == 3 {
@@ -795,7 +3057,11 @@ impl MixVM {
             Ok(())
         } else if instr.field == 2 || instr.field == 3 {
             // ENTx and ENNx
-            let new_word = HalfWord::from_i64(addr as i64).0;
+            let mut new_word = HalfWord::from_i64(addr as i64).0;
+            if addr == 0 && self.instr_negative {
+                // The sign of the instruction is loaded if M is zero.
+                new_word.set_sign(Sign::Negative);
+            }
             // Copy new word into reg.
             reg.clone_from(&new_word);
             if instr.field == 3 {
@@ -811,7 +3077,8 @@ impl MixVM {
     /// `F32ADD` and `F32SUB` are passed through if enabled.
     fn handle_instr_add_sub(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
         // Obtain V from memory.
-        let target_mem = &self.mem[self.helper_get_eff_addr(instr.addr, instr.index)?];
+        let target_mem =
+            &self.helper_read_mem(self.helper_get_mem_addr(instr.addr, instr.index)?)?;
 
         if cfg!(feature = "x-ieee754") && instr.field == 7 {
             // F32ADD, F32SUB
@@ -842,6 +3109,7 @@ impl MixVM {
                 self.overflow = true;
             }
         } else {
+            self.helper_check_field(instr.field)?;
             let orig_value = self.r_a.to_i64().0;
             let target_value = target_mem.to_i64_ranged(instr.field.to_range_inclusive()).0;
             // Calculate and pack new value.
@@ -850,7 +3118,13 @@ impl MixVM {
                 Opcode::Sub => orig_value - target_value,
                 _ => unreachable!(),
             };
-            let (new_word, overflow) = FullWord::from_i64(new_value);
+            let (mut new_word, overflow) = FullWord::from_i64(new_value);
+            if overflow && self.arith_mode == ArithMode::Saturating {
+                // Clamp to the range of the word, keeping the sign.
+                let min = FullWord::min_value(256) as i64;
+                let max = FullWord::max_magnitude(256) as i64;
+                new_word = FullWord::from_i64(new_value.clamp(min, max)).0;
+            }
             // Set new value.
             self.r_a.clone_from(&new_word);
             if overflow {
@@ -864,7 +3138,8 @@ impl MixVM {
     /// Handler for `MUL` and `F32MUL`.
     fn handle_instr_mul(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
         // Obtain V from memory.
-        let target_mem = &self.mem[self.helper_get_eff_addr(instr.addr, instr.index)?];
+        let target_mem =
+            &self.helper_read_mem(self.helper_get_mem_addr(instr.addr, instr.index)?)?;
         if cfg!(feature = "x-ieee754") && instr.field == 7 {
             // F32MUL
             let target_value =
@@ -890,39 +3165,30 @@ impl MixVM {
                 self.overflow = true;
             }
         } else {
+            self.helper_check_field(instr.field)?;
             let orig_value = self.r_a.to_i64().0;
             let target_value = target_mem.to_i64_ranged(instr.field.to_range_inclusive()).0;
-            // Copy value into registers.
+            // MUL cannot overflow rAX, as both operands fit in 5 bytes.
             let new_val = orig_value as i128 * target_value as i128;
-            let new_val_bytes = new_val.abs().to_be_bytes();
-            let mut new_val_bytes_dirty = new_val_bytes.map(|b| b != 0);
-            for (reg_i, byte_i) in (1..6).rev().zip((0..11).rev()) {
-                self.r_a[reg_i] = new_val_bytes[byte_i];
-                new_val_bytes_dirty[byte_i] = false;
-            }
-            for (reg_i, byte_i) in (1..6).rev().zip((11..16).rev()) {
-                self.r_x[reg_i] = new_val_bytes[byte_i];
-                new_val_bytes_dirty[byte_i] = false;
-            }
+            let new_val_abs = new_val.unsigned_abs();
+            // Copy value into registers.
+            (self.r_a, self.r_x) = FullWord::split_ax(new_val_abs);
             // Treat sign.
             let new_sign = if new_val < 0 {
-                FullWord::NEG
+                Sign::Negative
             } else {
-                FullWord::POS
+                Sign::Positive
             };
-            self.r_a[0] = new_sign;
-            self.r_x[0] = new_sign;
-            let overflow = new_val_bytes_dirty.iter().any(|b| *b);
-            if overflow {
-                self.overflow = overflow;
-            }
+            self.r_a.set_sign(new_sign);
+            self.r_x.set_sign(new_sign);
         }
         Ok(())
     }
 
     /// Handler for `DIV` and `F32DIV`.
     fn handle_instr_div(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
-        let target_mem = &self.mem[self.helper_get_eff_addr(instr.addr, instr.index)?];
+        let target_mem =
+            &self.helper_read_mem(self.helper_get_mem_addr(instr.addr, instr.index)?)?;
         if cfg!(feature = "x-ieee754") && instr.field == 7 {
             // F32DIV
             let target_value =
@@ -948,25 +3214,10 @@ impl MixVM {
                 self.overflow = true;
             }
         } else {
+            self.helper_check_field(instr.field)?;
             let target_value = target_mem.to_i64_ranged(instr.field.to_range_inclusive()).0 as i128;
-            let orig_value = i128::from_be_bytes([
-                0,
-                0,
-                0,
-                0,
-                0,
-                0,
-                self.r_a[1],
-                self.r_a[2],
-                self.r_a[3],
-                self.r_a[4],
-                self.r_a[5],
-                self.r_x[1],
-                self.r_x[2],
-                self.r_x[3],
-                self.r_x[4],
-                self.r_x[5],
-            ]) * self.r_a.get_sign() as i128;
+            let orig_value =
+                FullWord::concat_ax(&self.r_a, &self.r_x) as i128 * self.r_a.get_sign() as i128;
             // Calculate results.
             let quotient: i64 = orig_value
                 .checked_div(target_value)
@@ -995,12 +3246,13 @@ impl MixVM {
             // Copy results into registers.
             let (new_a, overflow_a) = FullWord::from_i64(quotient);
             let (new_x, overflow_x) = FullWord::from_i64(remainder);
-            self.r_x[0] = self.r_a[0];
-            self.r_a[0] = if orig_value.signum() == target_value.signum() {
-                FullWord::POS
-            } else {
-                FullWord::NEG
-            };
+            self.r_x.set_sign(self.r_a.sign());
+            self.r_a
+                .set_sign(if orig_value.signum() == target_value.signum() {
+                    Sign::Positive
+                } else {
+                    Sign::Negative
+                });
             self.r_a[1..=5].copy_from_slice(&new_a[1..=5]);
             self.r_x[1..=5].copy_from_slice(&new_x[1..=5]);
             if overflow_a || overflow_x {
@@ -1013,7 +3265,8 @@ impl MixVM {
     /// Handler for `CMPA` and `CMPX`, `F32CMPA` and `F32CMPX`.
     fn handle_instr_cmp_6b(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
         // Obtain CONTENT(M).
-        let target_mem = &self.mem[self.helper_get_eff_addr(instr.addr, instr.index)?];
+        let target_mem =
+            &self.helper_read_mem(self.helper_get_mem_addr(instr.addr, instr.index)?)?;
         let reg = match instr.opcode {
             Opcode::CmpA => &self.r_a,
             Opcode::CmpX => &self.r_x,
@@ -1034,22 +3287,21 @@ impl MixVM {
                 }
             };
         } else {
+            self.helper_check_field(instr.field)?;
             let target_value = target_mem.to_i64_ranged(instr.field.to_range_inclusive()).0;
             let reg_value = reg.to_i64_ranged(instr.field.to_range_inclusive()).0;
             // Calculate and set flags.
-            self.comp = match reg_value.cmp(&target_value) {
-                Ordering::Equal => CompIndicator::Equal,
-                Ordering::Less => CompIndicator::Less,
-                Ordering::Greater => CompIndicator::Greater,
-            };
+            self.comp = CompIndicator::from_i64_pair(reg_value, target_value);
         }
         Ok(())
     }
 
     /// Handler for `CMP1-6`.
     fn handle_instr_cmp_3b(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
+        self.helper_check_field(instr.field)?;
         // Obtain CONTENT(M).
-        let target_mem = &self.mem[self.helper_get_eff_addr(instr.addr, instr.index)?];
+        let target_mem =
+            &self.helper_read_mem(self.helper_get_mem_addr(instr.addr, instr.index)?)?;
         let target_value = target_mem.to_i64_ranged(instr.field.to_range_inclusive()).0;
         let reg = match instr.opcode {
             Opcode::Cmp1 => &self.r_in[1],
@@ -1063,21 +3315,13 @@ impl MixVM {
         let padded_reg = FullWord::from_bytes([reg[0], 0, 0, 0, reg[1], reg[2]]);
         let reg_value = padded_reg.to_i64_ranged(instr.field.to_range_inclusive()).0;
         // Calculate and set flags.
-        self.comp =
-            if (reg_value == target_value) || (reg_value.abs() == 0 && target_value.abs() == 0) {
-                // +0 and -0 are equal.
-                CompIndicator::Equal
-            } else if reg_value > target_value {
-                CompIndicator::Greater
-            } else {
-                CompIndicator::Less
-            };
+        self.comp = CompIndicator::from_i64_pair(reg_value, target_value);
         Ok(())
     }
 
     /// Handler for `JA` and `JX`.
     fn handle_instr_jmp_reg_6b(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
-        let target_addr = self.helper_get_eff_addr(instr.addr, instr.index)?;
+        let target_addr = self.helper_get_mem_addr(instr.addr, instr.index)?;
         let reg = match instr.opcode {
             Opcode::JA => &self.r_a,
             Opcode::JX => &self.r_x,
@@ -1117,7 +3361,7 @@ impl MixVM {
 
     /// Handler for `J1-6`.
     fn handle_instr_jmp_reg_3b(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
-        let target_addr = self.helper_get_eff_addr(instr.addr, instr.index)?;
+        let target_addr = self.helper_get_mem_addr(instr.addr, instr.index)?;
         let reg = match instr.opcode {
             Opcode::J1 => &self.r_in[1],
             Opcode::J2 => &self.r_in[2],
@@ -1162,27 +3406,7 @@ impl MixVM {
             || (cfg!(feature = "x-binary") && (instr.field == 6 || instr.field == 7))
         {
             // SLAX, SRAX, SLB and SRB.
-            // Spread original register to bytes.
-            let orig_a_bytes = &self.r_a[1..=5];
-            let orig_x_bytes = &self.r_x[1..=5];
-            let orig_value = u128::from_be_bytes([
-                0,
-                0,
-                0,
-                0,
-                0,
-                0,
-                orig_a_bytes[0],
-                orig_a_bytes[1],
-                orig_a_bytes[2],
-                orig_a_bytes[3],
-                orig_a_bytes[4],
-                orig_x_bytes[0],
-                orig_x_bytes[1],
-                orig_x_bytes[2],
-                orig_x_bytes[3],
-                orig_x_bytes[4],
-            ]);
+            let orig_value = FullWord::concat_ax(&self.r_a, &self.r_x);
             // Shift.
             let shifted_value = match instr.field {
                 2 => orig_value << (count * 8),
@@ -1191,53 +3415,21 @@ impl MixVM {
                 7 => orig_value >> count,
                 _ => unreachable!(),
             };
-            // Store back.
-            let shifted_bytes = shifted_value.to_be_bytes();
-            self.r_a[1..=5].copy_from_slice(&shifted_bytes[6..=10]);
-            self.r_x[1..=5].copy_from_slice(&shifted_bytes[11..=15]);
+            // Store back, keeping the signs.
+            let (new_a, new_x) = FullWord::split_ax(shifted_value);
+            self.r_a[1..=5].copy_from_slice(&new_a[1..=5]);
+            self.r_x[1..=5].copy_from_slice(&new_x[1..=5]);
         } else if instr.field == 4 || instr.field == 5 {
             // SLC and SRC.
-            // Spread out bytes.
-            let orig_bytes = [
-                self.r_a[1],
-                self.r_a[2],
-                self.r_a[3],
-                self.r_a[4],
-                self.r_a[5],
-                self.r_x[1],
-                self.r_x[2],
-                self.r_x[3],
-                self.r_x[4],
-                self.r_x[5],
-            ];
-            // Zero the registers.
-            self.r_a[1..=5].copy_from_slice(&[0; 5]);
-            self.r_x[1..=5].copy_from_slice(&[0; 5]);
-            // Create cyclic iterator.
-            let mut orig_bytes_iter = orig_bytes.iter().cycle();
-            // Get shift count.
-            let offset = if instr.field == 4 {
-                // SLC.
-                count % 10
-            } else {
-                // SRC.
-                10 - count % 10
-            };
-            for _ in 0..offset {
-                // Advance the iterator by `offset` steps,
-                // to simulate shifting.
-                // The iterator is infinite so we don't worry about
-                // panics.
-                orig_bytes_iter.next().or_else(|| unreachable!());
-            }
+            // Join rA and rX into a single magnitude.
+            let mut joined = Word::<11, false>::new();
+            joined[1..=5].copy_from_slice(&self.r_a[1..=5]);
+            joined[6..=10].copy_from_slice(&self.r_x[1..=5]);
+            // Rotate.
+            joined.rotate_magnitude(count as usize, instr.field == 4);
             // Write back.
-            for (reg_i, &digit) in (0..10).zip(orig_bytes_iter) {
-                if reg_i >= 5 {
-                    self.r_x[reg_i - 5 + 1] = digit;
-                } else {
-                    self.r_a[reg_i + 1] = digit;
-                }
-            }
+            self.r_a[1..=5].copy_from_slice(&joined[1..=5]);
+            self.r_x[1..=5].copy_from_slice(&joined[6..=10]);
         } else {
             return Err(ErrorCode::InvalidField);
         }
@@ -1254,12 +3446,12 @@ impl MixVM {
         // Call appropriate callbacks.
         let should_jump = match instr.opcode {
             Opcode::Jbus => dev.is_busy().map_err(|_| ErrorCode::IOError)?,
-            Opcode::Jred => dev.is_ready().map_err(|_| ErrorCode::IOError)?,
+            Opcode::Jred => dev.is_ready().map_err(|_| ErrorCode::IOError)? && !dev.at_eof(),
             _ => unreachable!(),
         };
         if should_jump {
             // Do jump.
-            let jump_addr = self.helper_get_eff_addr(instr.addr, instr.index)?;
+            let jump_addr = self.helper_get_mem_addr(instr.addr, instr.index)?;
             self.helper_do_jump(jump_addr, true);
         }
         Ok(())
@@ -1278,10 +3470,18 @@ impl MixVM {
         let command = self.helper_get_eff_addr_unchecked(instr.addr, instr.index);
         // Get device ID.
         let dev_id: usize = instr.field as usize;
+        let position = self.r_x.to_i64().0;
         // Get device reference.
         let dev = self.helper_get_io_device_mut(dev_id)?;
         // Call appropriate callbacks.
+        if Self::helper_is_disk(dev_id) {
+            dev.seek(position).map_err(|_| ErrorCode::IOError)?;
+        }
         dev.control(command).map_err(|_| ErrorCode::IOError)?;
+        self.helper_push_event(MachineEvent::DeviceIO {
+            unit: instr.field,
+            opcode: instr.opcode,
+        });
         Ok(())
     }
 
@@ -1295,38 +3495,69 @@ impl MixVM {
     #[cfg(feature = "io")]
     fn handle_instr_in_out(&mut self, instr: &Instruction) -> Result<(), ErrorCode> {
         // Check starting address.
-        let addr_start = self.helper_get_eff_addr(instr.addr, instr.index)?;
-        if !(0..Mem::SIZE as u16).contains(&addr_start) {
-            return Err(ErrorCode::InvalidAddress);
-        }
+        let addr_start = self.helper_get_mem_addr(instr.addr, instr.index)?;
         // Get device ID.
         let dev_id: usize = instr.field as usize;
+        let dev_blk_size = self.helper_get_io_device(dev_id)?.get_block_size();
+        // Check ending address. The block may cross the end of memory
+        // only if targets wrap around.
+        let addr_end = addr_start as usize + dev_blk_size;
+        let wraps = addr_end > Mem::SIZE;
+        if wraps && !self.address_wrap {
+            return Err(ErrorCode::InvalidAddress);
+        }
+        let addrs = (addr_start as usize..addr_end).map(|addr| (addr % Mem::SIZE) as u16);
+        if instr.opcode == Opcode::Out {
+            let uninit = addrs.clone().find(|&addr| !self.mem.is_initialized(addr));
+            if let Some(addr) = uninit {
+                if self.strict_uninit {
+                    return Err(ErrorCode::UninitializedRead);
+                }
+                self.helper_push_warning(RuntimeWarning::UninitializedRead {
+                    pc: self.instr_pc,
+                    addr,
+                });
+            }
+        }
         // Get device reference.
-        let dev = self
-            .io_devices
-            .get_mut(dev_id)
-            .ok_or(ErrorCode::InvalidField)?
+        let dev = self.io_devices[dev_id]
             .as_mut()
             .ok_or(ErrorCode::UnknownDevice)?;
-        let dev_blk_size = dev.get_block_size();
-        // Check ending address.
-        let addr_end = addr_start + dev_blk_size as u16;
-        if !(0..Mem::SIZE as u16).contains(&addr_end) {
-            return Err(ErrorCode::InvalidAddress);
+        if Self::helper_is_disk(dev_id) {
+            dev.seek(self.r_x.to_i64().0)
+                .map_err(|_| ErrorCode::IOError)?;
         }
-        // Call appropriate callbacks.
+        // Call appropriate callbacks. A wrapping block goes word by word.
         match instr.opcode {
+            Opcode::In if wraps => {
+                let mut buffer = std::vec![FullWord::new(); dev_blk_size];
+                dev.read(&mut buffer).map_err(|_| ErrorCode::IOError)?;
+                for (addr, word) in addrs.zip(buffer) {
+                    self.mem[addr] = word;
+                }
+                self.mem.count_writes(dev_blk_size);
+            }
             Opcode::In => {
-                let slice = &mut self.mem[addr_start as usize..addr_end as usize];
+                let slice = &mut self.mem[addr_start as usize..addr_end];
                 dev.read(slice).map_err(|_| ErrorCode::IOError)?;
+                self.mem.count_writes(dev_blk_size);
+            }
+            Opcode::Out if wraps => {
+                let words: Vec<FullWord> = addrs.map(|addr| self.mem[addr]).collect();
+                dev.write(&words).map_err(|_| ErrorCode::IOError)?;
+                self.mem.count_reads(dev_blk_size);
             }
             Opcode::Out => {
-                // Clone words.
-                let words = &self.mem[addr_start as usize..addr_end as usize];
+                let words = &self.mem[addr_start as usize..addr_end];
                 dev.write(words).map_err(|_| ErrorCode::IOError)?;
+                self.mem.count_reads(dev_blk_size);
             }
             _ => unreachable!(),
         };
+        self.helper_push_event(MachineEvent::DeviceIO {
+            unit: instr.field,
+            opcode: instr.opcode,
+        });
         Ok(())
     }
 
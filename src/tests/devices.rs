@@ -0,0 +1,624 @@
+use std::prelude::v1::*;
+
+use crate::*;
+
+#[test]
+fn test_tape_rewind() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.io_devices[0] = Some(Box::new(TapeDevice::new()));
+
+    mix.mem[0] = Instruction::new(1000, 0, 0, Opcode::Out).into();
+    mix.mem[1] = Instruction::new(1100, 0, 0, Opcode::Out).into();
+    mix.mem[2] = Instruction::new(0, 0, 0, Opcode::Ioc).into();
+    mix.mem[3] = Instruction::new(2000, 0, 0, Opcode::In).into();
+    mix.mem[1000] = FullWord::from_i64(1).0;
+    mix.mem[1100] = FullWord::from_i64(2).0;
+
+    mix.restart();
+
+    mix.step().unwrap();
+    mix.step().unwrap();
+    mix.step().unwrap();
+    mix.step().unwrap();
+    assert_eq!(mix.halted, false);
+    assert_eq!(mix.mem[2000][..], [0, 0, 0, 0, 0, 1]);
+}
+
+#[test]
+fn test_tape_skip() {
+    let mut tape = TapeDevice::new();
+    let block_1 = [FullWord::from_i64(1).0; TapeDevice::BLOCK_SIZE];
+    let block_2 = [FullWord::from_i64(2).0; TapeDevice::BLOCK_SIZE];
+    let mut buffer = [FullWord::new(); TapeDevice::BLOCK_SIZE];
+
+    tape.write(&block_1).unwrap();
+    tape.write(&block_2).unwrap();
+    assert_eq!(tape.position(), 2);
+    assert_eq!(tape.words().len(), 2 * TapeDevice::BLOCK_SIZE);
+
+    tape.control(-5).unwrap();
+    assert_eq!(tape.position(), 0);
+    tape.control(1).unwrap();
+    assert_eq!(tape.position(), 1);
+    tape.read(&mut buffer).unwrap();
+    assert_eq!(buffer[0][..], [0, 0, 0, 0, 0, 2]);
+    tape.read(&mut buffer).unwrap_err();
+}
+
+#[test]
+fn test_printer_ioc() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.io_devices[18] = Some(Box::new(PrinterDevice::new()));
+
+    mix.mem[0] = Instruction::new(1000, 18, 0, Opcode::Out).into();
+    mix.mem[1] = Instruction::new(0, 18, 0, Opcode::Ioc).into();
+    mix.mem[2] = Instruction::new(1000, 18, 0, Opcode::Out).into();
+    mix.mem[3] = Instruction::new(1, 18, 0, Opcode::Ioc).into();
+    mix.mem[1000].set_all([0, 8, 5, 13, 13, 16]);
+
+    mix.restart();
+
+    mix.step().unwrap();
+    mix.step().unwrap();
+    mix.step().unwrap();
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::IOError);
+}
+
+#[test]
+fn test_printer_lines() {
+    let mut printer = PrinterDevice::new();
+    let mut block = [FullWord::new(); PrinterDevice::BLOCK_SIZE];
+    block[0].set_all([0, 8, 5, 13, 13, 16]);
+
+    printer.write(&block).unwrap();
+    printer.control(0).unwrap();
+    printer.write(&block).unwrap();
+
+    let pages = printer.pages();
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[0].len(), 1);
+    assert_eq!(pages[1].len(), 1);
+    assert_eq!(pages[0][0].trim_end(), "HELLO");
+    assert_eq!(printer.lines().count(), 2);
+
+    block[1].set_all([0, 0, 0, 0, 0, 255]);
+    assert_eq!(printer.write(&block), Err(1));
+    printer.read(&mut block).unwrap_err();
+}
+
+#[test]
+fn test_device_ref() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.io_devices[18] = Some(Box::new(PrinterDevice::new()));
+
+    mix.mem[0] = Instruction::new(1000, 18, 0, Opcode::Out).into();
+    mix.mem[1000].set_all([0, 8, 5, 13, 13, 16]);
+
+    mix.restart();
+
+    mix.step().unwrap();
+
+    let printer = mix.device_ref::<PrinterDevice>(18).unwrap();
+    let lines: Vec<&str> = printer.lines().collect();
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].trim_end(), "HELLO");
+
+    assert!(mix.device_ref::<TapeDevice>(18).is_none());
+    assert!(mix.device_ref::<PrinterDevice>(17).is_none());
+    assert!(mix.device_ref::<PrinterDevice>(100).is_none());
+}
+
+/// A printer holding lines until flushed.
+struct BufferedPrinter {
+    buffer: Vec<FullWord>,
+    output: Vec<FullWord>,
+}
+
+impl IODevice for BufferedPrinter {
+    fn read(&mut self, _: &mut [FullWord]) -> Result<(), ()> {
+        Err(())
+    }
+
+    fn write(&mut self, data: &[FullWord]) -> Result<(), usize> {
+        self.buffer.extend_from_slice(data);
+        Ok(())
+    }
+
+    fn control(&mut self, _: i16) -> Result<(), ()> {
+        Err(())
+    }
+
+    fn is_busy(&self) -> Result<bool, ()> {
+        Ok(false)
+    }
+
+    fn is_ready(&self) -> Result<bool, ()> {
+        Ok(true)
+    }
+
+    fn get_block_size(&self) -> usize {
+        1
+    }
+
+    fn flush(&mut self) -> Result<(), ()> {
+        self.output.append(&mut self.buffer);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_flush_io() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.io_devices[18] = Some(Box::new(BufferedPrinter {
+        buffer: Vec::new(),
+        output: Vec::new(),
+    }));
+
+    mix.mem[0] = Instruction::new(1000, 18, 0, Opcode::Out).into();
+    mix.mem[1] = Instruction::new(1000, 18, 0, Opcode::Out).into();
+    mix.mem[2] = Instruction::new(0, 2, 0, Opcode::Special).into();
+    mix.mem[1000] = FullWord::from_i64(1).0;
+
+    mix.restart();
+
+    mix.step().unwrap();
+    let printer = mix.device_ref::<BufferedPrinter>(18).unwrap();
+    assert_eq!(printer.output.len(), 0);
+
+    mix.flush_io().unwrap();
+    let printer = mix.device_ref::<BufferedPrinter>(18).unwrap();
+    assert_eq!(printer.output.len(), 1);
+
+    mix.step().unwrap();
+    mix.step().unwrap();
+    assert_eq!(mix.halted, true);
+    let printer = mix.device_ref::<BufferedPrinter>(18).unwrap();
+    assert_eq!(printer.output.len(), 2);
+    assert_eq!(printer.buffer.len(), 0);
+}
+
+/// A device failing to flush.
+struct UnflushableDevice {}
+
+impl IODevice for UnflushableDevice {
+    fn read(&mut self, _: &mut [FullWord]) -> Result<(), ()> {
+        Err(())
+    }
+
+    fn write(&mut self, _: &[FullWord]) -> Result<(), usize> {
+        Err(0)
+    }
+
+    fn control(&mut self, _: i16) -> Result<(), ()> {
+        Err(())
+    }
+
+    fn is_busy(&self) -> Result<bool, ()> {
+        Ok(false)
+    }
+
+    fn is_ready(&self) -> Result<bool, ()> {
+        Ok(true)
+    }
+
+    fn get_block_size(&self) -> usize {
+        1
+    }
+
+    fn flush(&mut self) -> Result<(), ()> {
+        Err(())
+    }
+}
+
+#[test]
+fn test_flush_io_error_on_halt() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.io_devices[18] = Some(Box::new(UnflushableDevice {}));
+    mix.mem[0] = Instruction::new(0, 2, 0, Opcode::Special).into();
+
+    let reason = Rc::new(Cell::new(None));
+    let reason_hook = reason.clone();
+    mix.set_halt_hook(Box::new(move |mix| reason_hook.set(mix.halt_reason)));
+
+    mix.restart();
+
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::IOError);
+    assert_eq!(mix.halted, true);
+    assert_eq!(reason.get(), Some(HaltReason::Error(ErrorCode::IOError)));
+    assert_eq!(mix.halt_reason, Some(HaltReason::Error(ErrorCode::IOError)));
+}
+
+/// A device staying busy for a few checks after each write.
+struct SlowDevice {
+    busy_checks: core::cell::Cell<usize>,
+}
+
+impl IODevice for SlowDevice {
+    fn read(&mut self, _: &mut [FullWord]) -> Result<(), ()> {
+        Err(())
+    }
+
+    fn write(&mut self, _: &[FullWord]) -> Result<(), usize> {
+        self.busy_checks.set(3);
+        Ok(())
+    }
+
+    fn control(&mut self, _: i16) -> Result<(), ()> {
+        Err(())
+    }
+
+    fn is_busy(&self) -> Result<bool, ()> {
+        let checks = self.busy_checks.get();
+        self.busy_checks.set(checks.saturating_sub(1));
+        Ok(checks > 0)
+    }
+
+    fn is_ready(&self) -> Result<bool, ()> {
+        self.is_busy().map(|busy| !busy)
+    }
+
+    fn get_block_size(&self) -> usize {
+        1
+    }
+}
+
+#[test]
+fn test_halt_pending_io() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.io_devices[16] = Some(Box::new(SlowDevice {
+        busy_checks: core::cell::Cell::new(0),
+    }));
+
+    mix.mem[0] = Instruction::new(1000, 16, 0, Opcode::Out).into();
+    mix.mem[1] = Instruction::new(0, 2, 0, Opcode::Special).into();
+    mix.mem[2] = Instruction::new(0, 2, 0, Opcode::Special).into();
+
+    mix.restart();
+
+    mix.step().unwrap();
+    mix.step().unwrap();
+    assert_eq!(mix.halted, true);
+    assert_eq!(mix.halt_reason, Some(HaltReason::PendingIO(16)));
+
+    // Wait for the device.
+    let dev = mix.device_ref::<SlowDevice>(16).unwrap();
+    while dev.is_busy().unwrap() {}
+
+    mix.restart();
+
+    mix.step().unwrap();
+    assert_eq!(mix.halted, true);
+    assert_eq!(mix.halt_reason, Some(HaltReason::Hlt));
+}
+
+#[test]
+fn test_install_standard_devices() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.install_standard_devices(StandardDevicesConfig {
+        cards: vec!["HELLO".to_string(), "WORLD".to_string()],
+        ..Default::default()
+    });
+
+    assert!(mix.device_ref::<TapeDevice>(0).is_some());
+    assert!(mix.device_ref::<TapeDevice>(7).is_some());
+    assert!(mix.device_ref::<DiskDevice>(8).is_some());
+    assert!(mix.device_ref::<DiskDevice>(15).is_some());
+    assert!(mix.device_ref::<CardReaderDevice>(16).is_some());
+    assert!(mix.device_ref::<CardPunchDevice>(17).is_some());
+    assert!(mix.device_ref::<PrinterDevice>(18).is_some());
+    assert!(mix.device_ref::<TypewriterDevice>(19).is_some());
+    assert!(mix.device_ref::<PaperTapeDevice>(20).is_some());
+
+    // Copy a card to the printer.
+    mix.mem[0] = Instruction::new(1000, 16, 0, Opcode::In).into();
+    mix.mem[1] = Instruction::new(1000, 18, 0, Opcode::Out).into();
+
+    mix.restart();
+
+    mix.step().unwrap();
+    mix.step().unwrap();
+    assert_eq!(mix.mem[1000][..], [0, 8, 5, 13, 13, 16]);
+    assert_eq!(mix.mem[1001][..], [0, 0, 0, 0, 0, 0]);
+    let reader = mix.device_ref::<CardReaderDevice>(16).unwrap();
+    assert_eq!(reader.position(), 1);
+    let printer = mix.device_ref::<PrinterDevice>(18).unwrap();
+    assert_eq!(printer.lines().next().unwrap().trim_end(), "HELLO");
+}
+
+#[test]
+fn test_disk_seek() {
+    let mut disk = DiskDevice::new();
+    let block = [FullWord::from_i64(1).0; DiskDevice::BLOCK_SIZE];
+    let mut buffer = [FullWord::new(); DiskDevice::BLOCK_SIZE];
+
+    disk.seek(2).unwrap();
+    disk.write(&block).unwrap();
+    disk.read(&mut buffer).unwrap();
+    assert_eq!(buffer[0][..], [0, 0, 0, 0, 0, 1]);
+
+    disk.seek(5).unwrap();
+    disk.read(&mut buffer).unwrap();
+    assert_eq!(buffer[0][..], [0, 0, 0, 0, 0, 0]);
+
+    disk.seek(-1).unwrap_err();
+    disk.control(0).unwrap();
+    disk.control(2).unwrap_err();
+}
+
+#[test]
+fn test_disk_rx() {
+    let mut mix = MixVM::new();
+    mix.reset();
+    mix.install_standard_devices(StandardDevicesConfig::default());
+
+    mix.mem[0] = Instruction::new(2000, 5, 0, Opcode::LdX).into(); // LDX 2000
+    mix.mem[1] = Instruction::new(100, 8, 0, Opcode::Out).into(); // OUT 100(8)
+    mix.mem[2] = Instruction::new(300, 8, 0, Opcode::In).into(); // IN 300(8)
+    mix.mem[3] = Instruction::new(0, 2, 0, Opcode::ModifyX).into(); // ENTX 0
+    mix.mem[4] = Instruction::new(500, 8, 0, Opcode::In).into(); // IN 500(8)
+    mix.mem[5] = Instruction::new(1, 3, 0, Opcode::ModifyX).into(); // ENNX 1
+    mix.mem[6] = Instruction::new(0, 8, 0, Opcode::Ioc).into(); // IOC 0(8)
+    mix.mem[100] = FullWord::from_i64(42).0;
+    mix.mem[2000] = FullWord::from_i64(3).0;
+    mix.mem[500] = FullWord::from_i64(1).0;
+
+    mix.restart();
+
+    for _ in 0..5 {
+        mix.step().unwrap();
+    }
+    // Block 3 is written and read back, and block 0 is empty.
+    let disk = mix.device_ref::<DiskDevice>(8).unwrap();
+    assert_eq!(disk.words().len(), 4 * DiskDevice::BLOCK_SIZE);
+    assert_eq!(disk.words()[300][..], [0, 0, 0, 0, 0, 42]);
+    assert_eq!(mix.mem[300][..], [0, 0, 0, 0, 0, 42]);
+    assert_eq!(mix.mem[500][..], [0, 0, 0, 0, 0, 0]);
+
+    mix.step().unwrap();
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::IOError);
+}
+
+#[test]
+fn test_text_devices() {
+    let mut block = [FullWord::new(); TypewriterDevice::BLOCK_SIZE];
+
+    let mut typewriter = TypewriterDevice::new(vec!["ABC".to_string()]);
+    typewriter.read(&mut block).unwrap();
+    assert_eq!(block[0][..], [0, 1, 2, 3, 0, 0]);
+    typewriter.write(&block).unwrap();
+    assert_eq!(typewriter.output()[0].trim_end(), "ABC");
+    typewriter.read(&mut block).unwrap_err();
+
+    let mut punch = CardPunchDevice::new();
+    let mut card = [FullWord::new(); CardPunchDevice::BLOCK_SIZE];
+    card[0].set_all([0, 1, 2, 3, 4, 5]);
+    punch.write(&card).unwrap();
+    assert_eq!(punch.cards()[0].trim_end(), "ABCDE");
+
+    let mut reader = CardReaderDevice::new(vec!["A".repeat(81)]);
+    reader.read(&mut card).unwrap_err();
+}
+
+#[test]
+fn test_card_reader_short_card() {
+    let cards = vec!["A".repeat(80), "LAST".to_string()];
+    let mut mix = MixVM::new();
+    mix.reset();
+    mix.io_devices[16] = Some(Box::new(CardReaderDevice::new(cards.clone())));
+    for addr in 1000..1032 {
+        mix.mem[addr] = FullWord::from_i64(-1).0;
+    }
+
+    mix.execute(&Instruction::new(1000, 16, 0, Opcode::In))
+        .unwrap();
+    mix.execute(&Instruction::new(1016, 16, 0, Opcode::In))
+        .unwrap();
+    assert_eq!(mix.mem[1015][..], [0, 1, 1, 1, 1, 1]);
+    assert_eq!(mix.mem[1016][..], [0, 13, 1, 22, 23, 0]);
+    for addr in 1017..1032 {
+        assert_eq!(mix.mem[addr][..], [0, 0, 0, 0, 0, 0]);
+    }
+
+    mix.io_devices[16] = Some(Box::new(
+        CardReaderDevice::new(cards).with_pad_short_reads(false),
+    ));
+    mix.execute(&Instruction::new(1000, 16, 0, Opcode::In))
+        .unwrap();
+    let err = mix
+        .execute(&Instruction::new(1016, 16, 0, Opcode::In))
+        .expect_err("Expect error");
+    assert_eq!(err, ErrorCode::IOError);
+}
+
+#[test]
+fn test_card_reader_eof() {
+    let mut mix = MixVM::new();
+    mix.reset();
+    mix.io_devices[16] = Some(Box::new(CardReaderDevice::new(vec!["A".to_string()])));
+
+    let jred = Instruction::new(100, 16, 0, Opcode::Jred);
+    let jbus = Instruction::new(200, 16, 0, Opcode::Jbus);
+
+    mix.execute(&jred).unwrap();
+    assert_eq!(mix.pc, 100);
+
+    mix.pc = 0;
+    mix.execute(&Instruction::new(1000, 16, 0, Opcode::In))
+        .unwrap();
+    assert_eq!(mix.mem[1000][..], [0, 1, 0, 0, 0, 0]);
+
+    mix.execute(&jred).unwrap();
+    mix.execute(&jbus).unwrap();
+    assert_eq!(mix.pc, 0);
+
+    let err = mix
+        .execute(&Instruction::new(1000, 16, 0, Opcode::In))
+        .expect_err("Expect error");
+    assert_eq!(err, ErrorCode::IOError);
+}
+
+#[test]
+fn test_paper_tape() {
+    let mut paper_tape = PaperTapeDevice::new();
+    let mut block = [FullWord::new(); PaperTapeDevice::BLOCK_SIZE];
+    let mut buffer = [FullWord::new(); PaperTapeDevice::BLOCK_SIZE];
+    for (i, word) in block.iter_mut().enumerate() {
+        *word = FullWord::from_i64(-(i as i64)).0;
+    }
+
+    paper_tape.write(&block).unwrap();
+    assert_eq!(paper_tape.position(), 1);
+    paper_tape.read(&mut buffer).unwrap_err();
+
+    paper_tape.control(0).unwrap();
+    assert_eq!(paper_tape.position(), 0);
+    paper_tape.read(&mut buffer).unwrap();
+    assert_eq!(buffer, block);
+
+    paper_tape.control(1).unwrap_err();
+    paper_tape.write(&block[1..]).unwrap_err();
+}
+
+#[test]
+fn test_reset_devices() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.io_devices[0] = Some(Box::new(TapeDevice::new()));
+    mix.io_devices[16] = Some(Box::new(CardReaderDevice::new(vec!["A".to_string()])));
+
+    mix.mem[0] = Instruction::new(1000, 0, 0, Opcode::Out).into();
+    mix.mem[1] = Instruction::new(1100, 0, 0, Opcode::Out).into();
+    mix.mem[2] = Instruction::new(2000, 16, 0, Opcode::In).into();
+    mix.restart();
+    for _ in 0..3 {
+        mix.step().unwrap();
+    }
+    assert_eq!(mix.device_ref::<TapeDevice>(0).unwrap().position(), 2);
+    assert_eq!(
+        mix.device_ref::<CardReaderDevice>(16).unwrap().position(),
+        1
+    );
+
+    mix.reset();
+    let tape = mix.device_ref::<TapeDevice>(0).unwrap();
+    assert_eq!(tape.position(), 0);
+    assert_eq!(tape.words().len(), 2 * TapeDevice::BLOCK_SIZE);
+    assert_eq!(
+        mix.device_ref::<CardReaderDevice>(16).unwrap().position(),
+        0
+    );
+}
+
+#[test]
+fn test_disk_capacity() {
+    let mut mix = MixVM::new();
+    mix.reset();
+    mix.install_standard_devices(StandardDevicesConfig {
+        disk_capacity: 4,
+        ..Default::default()
+    });
+
+    mix.mem[0] = Instruction::new(2000, 5, 0, Opcode::LdX).into(); // LDX 2000
+    mix.mem[1] = Instruction::new(100, 8, 0, Opcode::Out).into(); // OUT 100(8)
+    mix.mem[2] = Instruction::new(2001, 5, 0, Opcode::LdX).into(); // LDX 2001
+    mix.mem[3] = Instruction::new(100, 8, 0, Opcode::Out).into(); // OUT 100(8)
+    mix.mem[4] = Instruction::new(2002, 5, 0, Opcode::LdX).into(); // LDX 2002
+    mix.mem[5] = Instruction::new(100, 9, 0, Opcode::In).into(); // IN 100(9)
+    mix.mem[2000] = FullWord::from_i64(3).0;
+    mix.mem[2001] = FullWord::from_i64(4).0;
+    mix.mem[2002] = FullWord::from_i64(1_000_000_000).0;
+
+    mix.restart();
+
+    mix.step().unwrap();
+    mix.step().unwrap();
+    mix.step().unwrap();
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::IOError);
+
+    mix.restart();
+    mix.pc = 4;
+
+    // A huge rX does not grow the disk.
+    mix.step().unwrap();
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::IOError);
+    let disk = mix.device_ref::<DiskDevice>(9).unwrap();
+    assert_eq!(disk.capacity(), 4);
+    assert!(disk.words().is_empty());
+}
+
+/// A writer shared with the test after being boxed.
+#[derive(Clone, Default)]
+struct SharedWriter(std::rc::Rc<std::cell::RefCell<Vec<u8>>>);
+
+impl std::io::Write for SharedWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_standard_devices_streams() {
+    let printed = SharedWriter::default();
+    let punched = SharedWriter::default();
+
+    let mut mix = MixVM::new();
+    mix.reset();
+    mix.install_standard_devices(StandardDevicesConfig {
+        cards: vec!["HELLO".to_string()],
+        cards_reader: Some(Box::new(std::io::Cursor::new("WORLD\r\n"))),
+        punch_writer: Some(Box::new(punched.clone())),
+        printer_writer: Some(Box::new(printed.clone())),
+        ..Default::default()
+    });
+
+    mix.mem[0] = Instruction::new(1000, 16, 0, Opcode::In).into(); // IN 1000(16)
+    mix.mem[1] = Instruction::new(1000, 18, 0, Opcode::Out).into(); // OUT 1000(18)
+    mix.mem[2] = Instruction::new(1000, 16, 0, Opcode::In).into(); // IN 1000(16)
+    mix.mem[3] = Instruction::new(1000, 17, 0, Opcode::Out).into(); // OUT 1000(17)
+    mix.mem[4] = Instruction::new(0, 18, 0, Opcode::Ioc).into(); // IOC 0(18)
+    mix.mem[5] = Instruction::new(7, 16, 0, Opcode::Jred).into(); // JRED 7(16)
+    mix.mem[6] = Instruction::new(0, 2, 0, Opcode::Special).into(); // HLT
+    mix.mem[7] = Instruction::new(0, 0, 0, Opcode::Nop).into(); // NOP
+
+    mix.restart();
+
+    for _ in 0..7 {
+        mix.step().unwrap();
+    }
+    assert_eq!(mix.halted, true);
+
+    let printed = String::from_utf8(printed.0.take()).unwrap();
+    let punched = String::from_utf8(punched.0.take()).unwrap();
+    assert_eq!(printed, format!("{:120}\n\x0c", "HELLO"));
+    assert_eq!(punched, format!("{:80}\n", "WORLD"));
+    let printer = mix.device_ref::<PrinterDevice>(18).unwrap();
+    assert!(printer.lines().next().is_none());
+}
@@ -12,6 +12,57 @@ fn test_clone() {
     assert_eq!(instr.opcode, instr2.opcode);
 }
 
+#[test]
+fn test_eq_debug() {
+    let instr = Instruction::new(2000, 5, 1, Opcode::LdA);
+    assert_eq!(instr, Instruction::new(2000, 5, 1, Opcode::LdA));
+    assert_ne!(instr, Instruction::new(2000, 5, 1, Opcode::LdX));
+    assert_eq!(
+        std::format!("{:?}", instr),
+        "Instruction { addr: 2000, field: 5, index: 1, opcode: LdA }"
+    );
+
+    let word: FullWord = instr.into();
+    assert_eq!(Instruction::try_from(word), Ok(instr));
+}
+
+#[test]
+fn test_with_field_index() {
+    let instr = Instruction::new(2000, 0, 0, Opcode::LdA).with_field(13);
+    assert_eq!(instr.addr, 2000);
+    assert_eq!(instr.field, 13);
+    assert_eq!(instr.index, 0);
+    assert_eq!(instr.opcode, Opcode::LdA);
+
+    let instr = instr.with_index(3);
+    assert_eq!(instr.addr, 2000);
+    assert_eq!(instr.field, 13);
+    assert_eq!(instr.index, 3);
+    assert_eq!(instr.opcode, Opcode::LdA);
+}
+
+#[test]
+fn test_field_lr() {
+    let instr = Instruction::new(2000, 13, 0, Opcode::LdA);
+    assert_eq!(instr.field_lr(), (1, 5));
+
+    let instr = instr.with_field_lr(0, 5);
+    assert_eq!(instr.field, 5);
+    assert_eq!(instr.field_lr(), (0, 5));
+}
+
+#[test]
+#[should_panic]
+fn test_field_lr_right_out_of_range() {
+    Instruction::new(2000, 0, 0, Opcode::LdA).with_field_lr(0, 9);
+}
+
+#[test]
+#[should_panic]
+fn test_field_lr_left_out_of_range() {
+    Instruction::new(2000, 0, 0, Opcode::LdA).with_field_lr(32, 0);
+}
+
 #[test]
 fn test_from_word() {
     let mut word = Word::<6, false>::new();
@@ -24,6 +75,19 @@ fn test_from_word() {
     assert_eq!(instr.addr, 2000);
 }
 
+#[test]
+fn test_as_instruction() {
+    let word = FullWord::from_bytes([1, 0x07, 0xD0, 0x02, 0x03, 0x08]);
+    let instr = word.as_instruction().unwrap();
+    assert_eq!(instr.opcode, Opcode::LdA);
+    assert_eq!(instr.field, 3);
+    assert_eq!(instr.index, 2);
+    assert_eq!(instr.addr, -2000);
+
+    let bad_word = FullWord::from_bytes([0, 0, 0, 0, 0, 255]);
+    assert!(bad_word.as_instruction().is_err());
+}
+
 #[test]
 fn test_into_word() {
     let instr = Instruction::new(2000, 0x03, 0x02, Opcode::LdA);
@@ -43,3 +107,105 @@ fn test_field_into_range_inclusive_signless() {
     assert_eq!(1.to_range_inclusive_signless(), (1..=1, true));
     assert_eq!(13.to_range_inclusive_signless(), (1..=5, false));
 }
+
+#[test]
+fn test_opcode_all() {
+    let all = Opcode::all();
+    assert_eq!(all.len(), 64);
+    for (i, &op) in all.iter().enumerate() {
+        assert_eq!(op as usize, i);
+    }
+}
+
+#[test]
+fn test_opcode_hash() {
+    use std::collections::HashMap;
+
+    let program = [
+        Instruction::new(2000, 5, 0, Opcode::LdA),
+        Instruction::new(2001, 5, 0, Opcode::Add),
+        Instruction::new(2002, 5, 0, Opcode::Add),
+        Instruction::new(0, 2, 0, Opcode::Special),
+    ];
+    let mut counts = HashMap::new();
+    for instr in &program {
+        *counts.entry(instr.opcode).or_insert(0) += 1;
+    }
+    assert_eq!(counts.len(), 3);
+    assert_eq!(counts[&Opcode::Add], 2);
+}
+
+#[test]
+fn test_opcode_classification() {
+    assert!(Opcode::Jmp.is_jump());
+    assert!(Opcode::JX.is_jump());
+    assert!(Opcode::Jbus.is_jump());
+    assert!(!Opcode::CmpA.is_jump());
+
+    assert!(Opcode::In.is_io());
+    assert!(Opcode::Ioc.is_io());
+    assert!(!Opcode::Move.is_io());
+
+    assert!(Opcode::Add.is_arithmetic());
+    assert!(Opcode::Div.is_arithmetic());
+    assert!(!Opcode::Shift.is_arithmetic());
+
+    let counts = Opcode::all().iter().fold((0, 0, 0), |(j, i, a), op| {
+        (
+            j + op.is_jump() as usize,
+            i + op.is_io() as usize,
+            a + op.is_arithmetic() as usize,
+        )
+    });
+    assert_eq!(counts, (11, 5, 4));
+}
+
+#[test]
+fn test_opcode_from_mnemonic() {
+    assert_eq!(Opcode::from_mnemonic("NOP"), Some((Opcode::Nop, 0)));
+    assert_eq!(Opcode::from_mnemonic("LDA"), Some((Opcode::LdA, 5)));
+    assert_eq!(Opcode::from_mnemonic("LD3N"), Some((Opcode::Ld3N, 5)));
+    assert_eq!(Opcode::from_mnemonic("STJ"), Some((Opcode::StJ, 2)));
+    assert_eq!(Opcode::from_mnemonic("MOVE"), Some((Opcode::Move, 1)));
+    assert_eq!(Opcode::from_mnemonic("HLT"), Some((Opcode::Special, 2)));
+    assert_eq!(Opcode::from_mnemonic("TRAP"), Some((Opcode::Special, 63)));
+    assert_eq!(Opcode::from_mnemonic("SLC"), Some((Opcode::Shift, 4)));
+    assert_eq!(Opcode::from_mnemonic("JNOV"), Some((Opcode::Jmp, 3)));
+    assert_eq!(Opcode::from_mnemonic("J5NP"), Some((Opcode::J5, 5)));
+    assert_eq!(Opcode::from_mnemonic("ENNA"), Some((Opcode::ModifyA, 3)));
+    assert_eq!(Opcode::from_mnemonic("DEC6"), Some((Opcode::Modify6, 1)));
+    assert_eq!(Opcode::from_mnemonic("CMPX"), Some((Opcode::CmpX, 5)));
+    assert_eq!(Opcode::from_mnemonic("lda"), None);
+    assert_eq!(Opcode::from_mnemonic("LD7"), None);
+    assert_eq!(Opcode::from_mnemonic(""), None);
+}
+
+#[test]
+fn test_opcode_mnemonic() {
+    assert_eq!(Opcode::Nop.mnemonic(0), Some("NOP"));
+    assert_eq!(Opcode::LdA.mnemonic(13), Some("LDA"));
+    assert_eq!(Opcode::Special.mnemonic(2), Some("HLT"));
+    assert_eq!(Opcode::Special.mnemonic(62), None);
+    assert_eq!(Opcode::Jmp.mnemonic(1), Some("JSJ"));
+    assert_eq!(Opcode::Modify3.mnemonic(2), Some("ENT3"));
+    assert_eq!(Opcode::Modify3.mnemonic(4), None);
+
+    // Every mnemonic maps back to its opcode and a field with the
+    // same mnemonic.
+    for &op in Opcode::all() {
+        for field in 0..64 {
+            if let Some(mnemonic) = op.mnemonic(field) {
+                let (from_op, from_field) = Opcode::from_mnemonic(mnemonic).unwrap();
+                assert_eq!(from_op, op);
+                assert_eq!(op.mnemonic(from_field), Some(mnemonic));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "x-binary")]
+#[test]
+fn test_opcode_from_mnemonic_binary() {
+    assert_eq!(Opcode::from_mnemonic("JAO"), Some((Opcode::JA, 7)));
+    assert_eq!(Opcode::from_mnemonic("SRB"), Some((Opcode::Shift, 7)));
+}
@@ -1,3 +1,6 @@
+use std::prelude::v1::*;
+
+use crate::instr::*;
 use crate::mem::*;
 
 #[test]
@@ -139,6 +142,24 @@ fn test_to_i64_ranged() {
     assert_eq!(value, 0xFF);
 }
 
+#[test]
+fn test_to_i64_ranged_sign() {
+    let word_neg = FullWord::from_bytes([1, 1, 2, 3, 4, 5]);
+
+    // The sign is ignored if excluded from the field.
+    let (value, _) = word_neg.to_i64_ranged(1..=5);
+    assert_eq!(value, 0x0102030405);
+    assert_eq!(value, word_neg.extract_field(13).to_i64().0);
+
+    let (value, _) = word_neg.to_i64_ranged(0..=5);
+    assert_eq!(value, -0x0102030405);
+    assert_eq!(value, word_neg.extract_field(5).to_i64().0);
+
+    let (value, _) = word_neg.to_i64_ranged(3..=4);
+    assert_eq!(value, 0x0304);
+    assert_eq!(value, word_neg.extract_field(28).to_i64().0);
+}
+
 #[test]
 fn test_toggle_sign() {
     let mut word = Word::<6, false>::new();
@@ -155,3 +176,317 @@ fn test_toggle_sign() {
     word_positive.flip_sign();
     assert_eq!(word_positive[0], 0);
 }
+
+#[test]
+fn test_sign() {
+    let mut word = Word::<6, false>::new();
+    assert_eq!(word.sign(), Sign::Positive);
+
+    word.set_sign(Sign::Negative);
+    assert_eq!(word.sign(), Sign::Negative);
+    assert_eq!(word[0], Word::<6, false>::NEG);
+    word.set_sign(Sign::Positive);
+    assert_eq!(word.sign(), Sign::Positive);
+    assert_eq!(word[0], Word::<6, false>::POS);
+
+    let mut word_positive = Word::<3, true>::new();
+    word_positive.set_sign(Sign::Negative);
+    assert_eq!(word_positive.sign(), Sign::Positive);
+    assert_eq!(word_positive[0], Word::<3, true>::POS);
+}
+
+#[test]
+fn test_find_opcode() {
+    let mut mem = Mem::new();
+    mem[0] = Instruction::new(2000, 5, 0, Opcode::LdA).into();
+    mem[1] = Instruction::new(3000, 0, 0, Opcode::Jmp).into();
+    mem[2] = Instruction::new(2000, 5, 0, Opcode::StA).into();
+    mem[100] = Instruction::new(0, 2, 0, Opcode::Jmp).into();
+    mem[101].set_all([0, 0, 0, 0, 0, 255]);
+    mem[3999] = Instruction::new(-1, 1, 0, Opcode::Jmp).into();
+
+    let found: Vec<u16> = mem.find_opcode(Opcode::Jmp).collect();
+    assert_eq!(found, [1, 100, 3999]);
+
+    let found: Vec<u16> = mem.find_opcode(Opcode::StA).collect();
+    assert_eq!(found, [2]);
+}
+
+#[cfg(feature = "x-ieee754")]
+#[test]
+fn test_fp_normalize() {
+    let word = Word::<6, false>::from_bytes([1, 130, 0, 0, 1, 2]);
+    let (normalized, underflow) = word.fp_normalize();
+    assert_eq!(underflow, false);
+    assert_eq!(normalized[..], [1, 128, 1, 2, 0, 0]);
+
+    let word_normal = Word::<6, false>::from_bytes([0, 64, 1, 2, 3, 4]);
+    let (normalized, underflow) = word_normal.fp_normalize();
+    assert_eq!(underflow, false);
+    assert_eq!(normalized[..], [0, 64, 1, 2, 3, 4]);
+
+    let word_underflow = Word::<6, false>::from_bytes([0, 1, 0, 0, 0, 5]);
+    let (normalized, underflow) = word_underflow.fp_normalize();
+    assert_eq!(underflow, true);
+    assert_eq!(normalized[..], [0, 254, 5, 0, 0, 0]);
+
+    let word_zero = Word::<6, false>::from_bytes([1, 50, 0, 0, 0, 0]);
+    let (normalized, underflow) = word_zero.fp_normalize();
+    assert_eq!(underflow, false);
+    assert_eq!(normalized[..], [1, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_extract_field() {
+    let word = FullWord::from_bytes([1, 10, 20, 30, 40, 50]);
+    assert_eq!(word.extract_field(11)[..], [0, 0, 0, 10, 20, 30]);
+    assert_eq!(word.extract_field(2)[..], [1, 0, 0, 0, 10, 20]);
+    assert_eq!(word.extract_field(5)[..], [1, 10, 20, 30, 40, 50]);
+    assert_eq!(word.extract_field(0)[..], [1, 0, 0, 0, 0, 0]);
+
+    let half_word = HalfWord::from_bytes([1, 10, 20]);
+    assert_eq!(half_word.extract_field(2)[..], [1, 0, 0, 0, 10, 20]);
+}
+
+#[test]
+fn test_word_arith() {
+    let a = FullWord::from_i64(300).0;
+    let b = FullWord::from_i64(-500).0;
+
+    assert_eq!((a + b)[..], [1, 0, 0, 0, 0, 200]);
+    assert_eq!((a - b)[..], [0, 0, 0, 0, 3, 32]);
+    assert_eq!((a * b)[..], [1, 0, 0, 2, 73, 240]);
+    assert_eq!((b - b)[..], [0, 0, 0, 0, 0, 0]);
+
+    let max = FullWord::from_i64(0xFF_FFFF_FFFF).0;
+    assert_eq!(max.checked_add(a), None);
+    assert_eq!(max.checked_mul(b), None);
+    assert_eq!(max.checked_sub(b), None);
+    assert_eq!(
+        max.checked_sub(a),
+        Some(FullWord::from_i64(0xFF_FFFF_FFFF - 300).0)
+    );
+
+    let pos_a = PosHalfWord::from_i64(1).0;
+    let pos_b = PosHalfWord::from_i64(2).0;
+    assert_eq!(pos_a.checked_sub(pos_b), None);
+    assert_eq!(pos_b.checked_sub(pos_a), Some(pos_a));
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn test_word_arith_overflow() {
+    let max = HalfWord::from_i64(0xFFFF).0;
+    let _ = max + max;
+}
+
+#[test]
+fn test_word_display() {
+    let word = FullWord::from_bytes([0, 0, 1, 10, 63, 255]);
+    assert_eq!(word.to_string(), "+ 000 001 010 063 255");
+
+    let half_word = HalfWord::from_bytes([1, 2, 3]);
+    assert_eq!(half_word.to_string(), "- 002 003");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_to_mix_number_string() {
+    let word = FullWord::from_bytes([0, 0, 1, 23, 45, 99]);
+    assert_eq!(word.to_mix_number_string(100).unwrap(), "+0001234599");
+
+    let word = FullWord::from_bytes([1, 0, 0, 0, 0, 7]);
+    assert_eq!(word.to_mix_number_string(100).unwrap(), "-0000000007");
+
+    let half_word = HalfWord::from_bytes([0, 63, 1]);
+    assert_eq!(half_word.to_mix_number_string(64).unwrap(), "+6301");
+
+    let word = FullWord::from_bytes([0, 1, 2, 3, 4, 5]);
+    assert_eq!(word.to_mix_number_string(10).unwrap(), "+12345");
+    assert_eq!(word.to_mix_number_string(5), Err(()));
+    assert_eq!(word.to_mix_number_string(1), Err(()));
+}
+
+#[test]
+fn test_word_hash() {
+    use std::collections::HashSet;
+
+    let mut set = HashSet::new();
+    set.insert(FullWord::from_bytes([0, 0, 0, 0, 0, 0]));
+    set.insert(FullWord::from_bytes([1, 0, 0, 0, 0, 0]));
+    set.insert(FullWord::from_i64(0).0);
+    set.insert(FullWord::from_i64(1).0);
+    set.insert(FullWord::from_i64(1).0);
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&FullWord::from_bytes([1, 0, 0, 0, 0, 0])));
+}
+
+#[test]
+fn test_word_capacity() {
+    assert_eq!(Word::<6, false>::bit_width(), 40);
+    assert_eq!(Word::<6, false>::max_magnitude(64), 1_073_741_823);
+    assert_eq!(Word::<6, false>::max_magnitude(100), 9_999_999_999);
+    assert_eq!(Word::<6, false>::max_magnitude(256), 0xFF_FFFF_FFFF);
+    assert_eq!(Word::<6, false>::min_value(64), -1_073_741_823);
+    assert_eq!(Word::<6, false>::min_value(100), -9_999_999_999);
+    assert_eq!(Word::<6, true>::min_value(100), 0);
+
+    assert_eq!(Word::<1, false>::max_magnitude(100), 0);
+    assert_eq!(Word::<6, false>::max_magnitude(0), 0);
+    assert_eq!(Word::<20, false>::max_magnitude(256), u128::MAX);
+    assert_eq!(Word::<20, false>::min_value(256), -i128::MAX);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_card_field() {
+    let word = FullWord::from_bytes([1, 10, 20, 30, 40, 99]);
+    let card = word.to_card_field().unwrap();
+    assert_eq!(card, "-1020304099");
+    assert_eq!(FullWord::from_card_field(&card), Ok(word));
+
+    assert_eq!(
+        HalfWord::from_card_field("0102"),
+        Ok(HalfWord::from_bytes([0, 1, 2]))
+    );
+    assert_eq!(
+        PosHalfWord::from_card_field("-0102"),
+        Ok(PosHalfWord::from_bytes([0, 1, 2]))
+    );
+    assert_eq!(FullWord::from_card_field("+000000123"), Err(()));
+    assert_eq!(FullWord::from_card_field("+00000012x4"), Err(()));
+    assert_eq!(
+        FullWord::from_bytes([0, 0, 0, 0, 0, 100]).to_card_field(),
+        Err(())
+    );
+}
+
+#[test]
+fn test_set_field() {
+    let reg = FullWord::from_bytes([1, 6, 7, 8, 9, 0]);
+
+    let mut word = FullWord::from_bytes([0, 1, 2, 3, 4, 5]);
+    word.set_field(18, &reg);
+    assert_eq!(word[..], [0, 1, 0, 3, 4, 5]);
+
+    let mut word = FullWord::from_bytes([0, 1, 2, 3, 4, 5]);
+    word.set_field(3, &reg);
+    assert_eq!(word[..], [1, 8, 9, 0, 4, 5]);
+
+    let mut word = FullWord::from_bytes([0, 1, 2, 3, 4, 5]);
+    word.set_field(45, &reg);
+    assert_eq!(word[..], [0, 1, 2, 3, 4, 0]);
+
+    let mut word = PosHalfWord::from_bytes([0, 1, 2]);
+    word.set_field(2, &reg);
+    assert_eq!(word[..], [0, 9, 0]);
+}
+
+#[test]
+fn test_rotate_magnitude() {
+    let mut word = FullWord::from_bytes([1, 1, 2, 3, 4, 5]);
+    word.rotate_magnitude(2, true);
+    assert_eq!(word[..], [1, 3, 4, 5, 1, 2]);
+
+    let mut word = FullWord::from_bytes([0, 1, 2, 3, 4, 5]);
+    word.rotate_magnitude(7, false);
+    assert_eq!(word[..], [0, 4, 5, 1, 2, 3]);
+
+    let mut word = PosHalfWord::from_bytes([0, 1, 2]);
+    word.rotate_magnitude(0, true);
+    assert_eq!(word[..], [0, 1, 2]);
+}
+
+#[test]
+fn test_word_neg() {
+    let word_pos = FullWord::from_bytes([0, 1, 2, 3, 4, 5]);
+    assert_eq!((-word_pos)[..], [1, 1, 2, 3, 4, 5]);
+
+    let word_neg = HalfWord::from_bytes([1, 1, 2]);
+    assert_eq!((-word_neg)[..], [0, 1, 2]);
+
+    let word_always_pos = PosHalfWord::from_bytes([0, 1, 2]);
+    assert_eq!((-word_always_pos)[..], [0, 1, 2]);
+}
+
+#[test]
+fn test_from_i64_checked() {
+    let word = FullWord::from_i64_checked(-0xFF_FFFF_FFFF).unwrap();
+    assert_eq!(word[..], [1, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+    let err = FullWord::from_i64_checked(0x100_0000_0000).unwrap_err();
+    assert_eq!(err.value, 0x100_0000_0000);
+    assert_eq!(err.max_magnitude, 0xFF_FFFF_FFFF);
+
+    let err = PosHalfWord::from_i64_checked(-0x10000).unwrap_err();
+    assert_eq!(err.max_magnitude, 0xFFFF);
+
+    let big_word = Word::<10, false>::from_i64_checked(i64::MIN + 1).unwrap();
+    assert_eq!(big_word.to_i64().0, i64::MIN + 1);
+
+    let (word, overflow) = Word::<10, false>::from_i64(i64::MIN);
+    assert_eq!(overflow, false);
+    assert_eq!(word[..], [1, 0, 0x80, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_from_chars() {
+    let word = FullWord::from_chars(['H', 'E', 'L', 'L', 'O']).unwrap();
+    assert_eq!(word[..], [0, 8, 5, 13, 13, 16]);
+    assert_eq!(word.to_i64().0, 0x08050D0D10);
+
+    assert_eq!(FullWord::from_chars(['H', 'E', 'L', 'L', '!']), Err('!'));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_octal_string() {
+    let word = FullWord::from_bytes([1, 0, 7, 8, 64, 255]);
+    let octal = word.to_octal_string();
+    assert_eq!(octal, "- 000 007 010 100 377");
+    assert_eq!(FullWord::from_octal_string(&octal), Ok(word));
+
+    let word = HalfWord::from_octal_string("12 0").unwrap();
+    assert_eq!(word[..], [0, 10, 0]);
+    assert_eq!(word.to_octal_string(), "+ 012 000");
+
+    assert!(FullWord::from_octal_string("- 0 0 0 0 0 0").is_err());
+    assert!(FullWord::from_octal_string("- 0 0 0 0 +1").is_err());
+}
+
+#[test]
+fn test_mem_in_bounds() {
+    assert!(Mem::in_bounds(0));
+    assert!(Mem::in_bounds(Mem::SIZE as u16 - 1));
+    assert!(!Mem::in_bounds(Mem::SIZE as u16));
+    assert!(!Mem::in_bounds(u16::MAX));
+}
+
+#[test]
+fn test_try_from_i64() {
+    let word = FullWord::try_from(-2000i64).unwrap();
+    assert_eq!(word[..], [1, 0, 0, 0, 0x07, 0xD0]);
+    let word: FullWord = 0xFF_FFFF_FFFFi64.try_into().unwrap();
+    assert_eq!(word[..], [0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+    let err = FullWord::try_from(0x100_0000_0000i64).unwrap_err();
+    assert_eq!(err.value, 0x100_0000_0000);
+    assert_eq!(err.max_magnitude, 0xFF_FFFF_FFFF);
+}
+
+#[test]
+fn test_concat_split_ax() {
+    let a = FullWord::from_bytes([1, 1, 2, 3, 4, 5]);
+    let x = FullWord::from_bytes([0, 6, 7, 8, 9, 10]);
+    let value = FullWord::concat_ax(&a, &x);
+    assert_eq!(value, 0x01_02_03_04_05_06_07_08_09_0A);
+
+    let (new_a, new_x) = FullWord::split_ax(value);
+    assert_eq!(new_a[..], [0, 1, 2, 3, 4, 5]);
+    assert_eq!(new_x[..], [0, 6, 7, 8, 9, 10]);
+
+    let (new_a, new_x) = FullWord::split_ax(u128::MAX);
+    assert_eq!(new_a[..], [0, 255, 255, 255, 255, 255]);
+    assert_eq!(new_x[..], [0, 255, 255, 255, 255, 255]);
+}
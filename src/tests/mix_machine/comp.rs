@@ -145,3 +145,16 @@ fn test_exp_13() {
 
     assert_eq!(mix.r_a[..], [0, 0, 0, 0x18, 0x53, 0xD3]);
 }
+
+#[test]
+fn test_comp_from_i64_pair() {
+    let zero = FullWord::from_bytes([0, 0, 0, 0, 0, 0]);
+    let neg_zero = FullWord::from_bytes([1, 0, 0, 0, 0, 0]);
+    assert_eq!(
+        CompIndicator::from_i64_pair(zero.to_i64().0, neg_zero.to_i64().0),
+        CompIndicator::Equal
+    );
+    assert_eq!(CompIndicator::from_i64_pair(-3, 2), CompIndicator::Less);
+    assert_eq!(CompIndicator::from_i64_pair(5, 5), CompIndicator::Equal);
+    assert_eq!(CompIndicator::from_i64_pair(2, -3), CompIndicator::Greater);
+}
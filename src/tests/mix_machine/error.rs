@@ -38,6 +38,28 @@ fn test_invalid_addr() {
     assert_eq!(mix.halted, true);
 }
 
+#[test]
+fn test_invalid_mem_addr() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(4000, 5, 0, Opcode::StA).into();
+    mix.mem[1] = Instruction::new(3999, 5, 1, Opcode::StZ).into();
+    mix.mem[2] = Instruction::new(3999, 5, 1, Opcode::LdA).into();
+    mix.mem[3] = Instruction::new(4000, 0, 0, Opcode::Jmp).into();
+
+    mix.r_in[1] = HalfWord::from_i64(1).0;
+
+    for pc in 0..4 {
+        mix.pc = pc;
+        mix.restart();
+
+        let err = mix.step().expect_err("Expect error");
+        assert_eq!(err, ErrorCode::InvalidAddress);
+        assert_eq!(mix.halted, true);
+    }
+}
+
 #[test]
 fn test_invalid_field() {
     let mut mix = MixVM::new();
@@ -65,3 +87,108 @@ fn test_invalid_index() {
     assert_eq!(err, ErrorCode::InvalidIndex);
     assert_eq!(mix.halted, true);
 }
+
+#[test]
+fn test_invalid_move() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(3998, 5, 0, Opcode::Move).into();
+    mix.mem[1] = Instruction::new(1000, 5, 0, Opcode::Move).into();
+
+    mix.r_in[1] = HalfWord::from_i64(1000).0;
+
+    mix.restart();
+
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::InvalidAddress);
+    assert_eq!(mix.halted, true);
+    assert_eq!(mix.r_in[1][..], [0, 0x03, 0xE8]);
+
+    mix.pc = 1;
+    mix.r_in[1] = HalfWord::from_i64(3998).0;
+
+    mix.restart();
+
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::InvalidAddress);
+    assert_eq!(mix.halted, true);
+}
+
+#[test]
+fn test_uninitialized_read() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(2000, 5, 0, Opcode::LdA).into();
+    mix.r_a = FullWord::from_i64(1).0;
+
+    mix.restart();
+
+    mix.step().unwrap();
+    assert_eq!(mix.r_a[..], [0, 0, 0, 0, 0, 0]);
+
+    mix.reset();
+    mix.strict_uninit = true;
+    mix.restart();
+
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::UninitializedRead);
+    assert_eq!(mix.halted, true);
+
+    mix.clear_memory();
+    mix.reset();
+    mix.restart();
+
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::UninitializedRead);
+    assert_eq!(mix.halted, true);
+}
+
+#[test]
+fn test_invalid_field_decode() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    // LDA 1000(25:0)
+    mix.mem[0].set_all([0, 0x03, 0xE8, 0, 200, 8]);
+    // ADD 1000(5:7)
+    mix.mem[1].set_all([0, 0x03, 0xE8, 0, 47, 1]);
+    // STA 1000(0:6)
+    mix.mem[2].set_all([0, 0x03, 0xE8, 0, 6, 24]);
+
+    let instr = Instruction::try_from(mix.mem[0]).unwrap();
+    assert_eq!(instr.opcode, Opcode::LdA);
+    assert_eq!(instr.field, 200);
+
+    for pc in 0..3 {
+        mix.pc = pc;
+        mix.restart();
+
+        let err = mix.step().expect_err("Expect error");
+        assert_eq!(err, ErrorCode::InvalidField);
+        assert_eq!(mix.halted, true);
+    }
+}
+
+#[test]
+fn test_fault_pc() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(0, 0, 0, Opcode::Nop).into();
+    mix.mem[1] = Instruction::new(1000, 63, 0, Opcode::Jmp).into();
+
+    mix.restart();
+
+    mix.step().unwrap();
+    assert_eq!(mix.fault_pc, None);
+
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::InvalidField);
+    assert_eq!(mix.fault_pc, Some(1));
+    assert_eq!(mix.pc, 2);
+
+    mix.restart();
+    assert_eq!(mix.fault_pc, None);
+}
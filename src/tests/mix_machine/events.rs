@@ -0,0 +1,248 @@
+use crate::*;
+
+#[test]
+fn test_events() {
+    let mut mix = MixVM::new();
+    mix.reset();
+    mix.recording = true;
+
+    mix.mem[0] = Instruction::new(2000, 5, 0, Opcode::LdA).into();
+    mix.mem[1] = Instruction::new(2001, 5, 0, Opcode::StA).into();
+    mix.mem[2] = Instruction::new(100, 0, 0, Opcode::Jmp).into();
+    mix.mem[100] = Instruction::new(2000, 5, 0, Opcode::Add).into();
+    mix.mem[2000].set_all([0, 255, 255, 255, 255, 255]);
+
+    mix.restart();
+
+    mix.step().unwrap();
+    mix.step().unwrap();
+    mix.step().unwrap();
+    mix.step().unwrap();
+
+    let events = &mix.events;
+    assert_eq!(events.len(), 6);
+    assert!(matches!(
+        events[0],
+        MachineEvent::RegisterWrite {
+            register: Register::A,
+            ..
+        }
+    ));
+    assert!(matches!(
+        events[1],
+        MachineEvent::MemoryWrite { addr: 2001, value } if value[..] == [0, 255, 255, 255, 255, 255]
+    ));
+    assert!(matches!(events[2], MachineEvent::Jump { from: 2, to: 100 }));
+    assert!(matches!(
+        events[3],
+        MachineEvent::RegisterWrite {
+            register: Register::J,
+            value
+        } if value[..] == [0, 0, 0, 0, 0, 3]
+    ));
+    assert!(matches!(
+        events[4],
+        MachineEvent::RegisterWrite {
+            register: Register::A,
+            ..
+        }
+    ));
+    assert!(matches!(events[5], MachineEvent::OverflowSet));
+}
+
+#[test]
+fn test_events_jump_pc_wrap() {
+    let mut mix = MixVM::new();
+    mix.reset();
+    mix.recording = true;
+    mix.pc_wrap = true;
+
+    mix.mem[3999] = Instruction::new(100, 0, 0, Opcode::Jmp).into();
+
+    mix.restart();
+    mix.pc = 3999;
+
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 100);
+    assert!(matches!(
+        mix.events[0],
+        MachineEvent::Jump {
+            from: 3999,
+            to: 100
+        }
+    ));
+    assert_eq!(mix.r_j[..], [0, 0, 0]);
+}
+
+#[test]
+fn test_events_not_recording() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(100, 0, 0, Opcode::Jmp).into();
+
+    mix.restart();
+
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 100);
+    assert!(mix.events.is_empty());
+}
+
+#[cfg(feature = "io")]
+#[test]
+fn test_events_device_io() {
+    let mut mix = MixVM::new();
+    mix.reset();
+    mix.recording = true;
+
+    mix.io_devices[0] = Some(std::boxed::Box::new(TapeDevice::new()));
+
+    mix.mem[0] = Instruction::new(1000, 0, 0, Opcode::Out).into();
+    mix.mem[1] = Instruction::new(0, 0, 0, Opcode::Ioc).into();
+
+    mix.restart();
+
+    mix.step().unwrap();
+    mix.step().unwrap();
+
+    assert_eq!(mix.events.len(), 2);
+    assert!(matches!(
+        mix.events[0],
+        MachineEvent::DeviceIO {
+            unit: 0,
+            opcode: Opcode::Out
+        }
+    ));
+    assert!(matches!(
+        mix.events[1],
+        MachineEvent::DeviceIO {
+            unit: 0,
+            opcode: Opcode::Ioc
+        }
+    ));
+}
+
+#[test]
+fn test_trace_to_writer() {
+    use std::cell::RefCell;
+    use std::io::Write;
+    use std::rc::Rc;
+    use std::string::String;
+    use std::vec::Vec;
+
+    struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(1000, 0, 0, Opcode::Jmp).into();
+    mix.mem[1000] = Instruction::new(2000, 2, 0, Opcode::Jmp).into();
+    mix.mem[1001] = Instruction::new(0, 1, 0, Opcode::Jmp).into();
+
+    let buffer = Rc::new(RefCell::new(Vec::new()));
+    mix.trace_to_writer(SharedBuffer(buffer.clone()));
+    mix.restart();
+
+    mix.step().unwrap();
+    mix.step().unwrap();
+    mix.step().unwrap();
+
+    let log = String::from_utf8(buffer.borrow().clone()).unwrap();
+    let lines: Vec<&str> = log.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            "0000 JMP 1000,0(0) rJ=+ 000 001",
+            "1000 JOV 2000,0(2)",
+            "1001 JSJ 0,0(1)",
+        ]
+    );
+}
+
+#[test]
+fn test_halt_hook() {
+    use std::boxed::Box;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let mut mix = MixVM::new();
+
+    // Sum 1 to 10 into rA.
+    mix.mem[0] = Instruction::new(10, 2, 0, Opcode::Modify1).into(); // ENT1 10
+    mix.mem[1] = Instruction::new(0, 0, 1, Opcode::ModifyA).into(); // INCA 0,1
+    mix.mem[2] = Instruction::new(1, 1, 0, Opcode::Modify1).into(); // DEC1 1
+    mix.mem[3] = Instruction::new(1, 2, 0, Opcode::J1).into(); // J1P 1
+    mix.mem[4] = Instruction::new(0, 2, 0, Opcode::Special).into(); // HLT
+
+    let result = Rc::new(Cell::new(None));
+    let result_hook = result.clone();
+    mix.set_halt_hook(Box::new(move |mix| {
+        result_hook.set(Some((mix.r_a.to_i64().0, mix.halt_reason)));
+    }));
+    mix.reset_and_run();
+    assert_eq!(mix.run_until_halt().unwrap().stop, StopReason::Halted);
+    assert_eq!(result.get(), Some((55, Some(HaltReason::Hlt))));
+
+    // Halting on an error does not call the hook.
+    result.set(None);
+    mix.mem[4] = Instruction::new(0, 50, 0, Opcode::Special).into();
+    mix.reset_and_run();
+    mix.run_until_halt().unwrap_err();
+    assert_eq!(result.get(), None);
+}
+
+#[test]
+fn test_event_recorder() {
+    let mut mix = MixVM::new();
+    for (addr, word) in super::maximum_program(0) {
+        mix.mem[addr] = word;
+    }
+    mix.reset_and_run();
+    mix.r_in[1] = HalfWord::from_i64(5).0;
+
+    let mut recorder = mix.into_event_recorder();
+    let report = recorder.machine_mut().run_until_halt().unwrap();
+    assert_eq!(report.steps, 25);
+    let (mix, log) = recorder.into_parts();
+    assert_eq!(mix.r_a.to_i64().0, 9);
+    assert_eq!(
+        log,
+        "\
+0000 ENT3 0,1(2) rI3=+ 000 005
+0001 JMP 4,0(0) rJ=+ 000 002
+0004 ENT2 0,3(2) rI2=+ 000 005
+0005 LDA 1000,3(5) rA=+ 000 000 000 000 004
+0006 DEC3 1,0(1) rI3=+ 000 004
+0007 J3P 2,0(2) rJ=+ 000 008
+0002 CMPA 1000,3(5) CI=L
+0003 JGE 6,0(7)
+0004 ENT2 0,3(2) rI2=+ 000 004
+0005 LDA 1000,3(5) rA=+ 000 000 000 000 009
+0006 DEC3 1,0(1) rI3=+ 000 003
+0007 J3P 2,0(2)
+0002 CMPA 1000,3(5) CI=G
+0003 JGE 6,0(7) rJ=+ 000 004
+0006 DEC3 1,0(1) rI3=+ 000 002
+0007 J3P 2,0(2) rJ=+ 000 008
+0002 CMPA 1000,3(5) CI=E
+0003 JGE 6,0(7) rJ=+ 000 004
+0006 DEC3 1,0(1) rI3=+ 000 001
+0007 J3P 2,0(2) rJ=+ 000 008
+0002 CMPA 1000,3(5) CI=G
+0003 JGE 6,0(7) rJ=+ 000 004
+0006 DEC3 1,0(1) rI3=+ 000 000
+0007 J3P 2,0(2)
+0008 HLT 0,0(2)
+"
+    );
+}
@@ -0,0 +1,125 @@
+use crate::*;
+
+#[test]
+fn test_step_back() {
+    let mut mix = MixVM::new();
+    mix.reset();
+    mix.enable_history(16);
+
+    mix.mem[0] = Instruction::new(2000, 5, 0, Opcode::LdA).into();
+    mix.mem[1] = Instruction::new(2001, 5, 0, Opcode::StA).into();
+    mix.mem[2] = Instruction::new(100, 0, 0, Opcode::Jmp).into();
+    mix.mem[2000].set_all([1, 1, 2, 3, 4, 5]);
+    mix.mem[2001].set_all([0, 6, 7, 8, 9, 10]);
+
+    mix.restart();
+
+    mix.step().unwrap();
+    mix.step().unwrap();
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 100);
+    assert_eq!(mix.r_j[..], [0, 0, 3]);
+    assert_eq!(mix.mem[2001][..], [1, 1, 2, 3, 4, 5]);
+
+    mix.step_back().unwrap();
+    mix.step_back().unwrap();
+    assert_eq!(mix.pc, 1);
+    assert_eq!(mix.halted, false);
+    assert_eq!(mix.r_a[..], [1, 1, 2, 3, 4, 5]);
+    assert_eq!(mix.r_j[..], [0, 0, 0]);
+    assert_eq!(mix.mem[2001][..], [0, 6, 7, 8, 9, 10]);
+
+    mix.step_back().unwrap();
+    assert_eq!(mix.pc, 0);
+    assert_eq!(mix.r_a[..], [0, 0, 0, 0, 0, 0]);
+
+    let err = mix.step_back().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::NoHistory);
+}
+
+#[test]
+fn test_step_back_limit() {
+    let mut mix = MixVM::new();
+    mix.reset();
+    mix.enable_history(1);
+
+    mix.mem[0] = Instruction::new(1, 0, 0, Opcode::ModifyA).into();
+    mix.mem[1] = Instruction::new(1, 0, 0, Opcode::ModifyA).into();
+    mix.mem[2] = Instruction::new(0, 2, 0, Opcode::Special).into();
+
+    mix.restart();
+
+    mix.step().unwrap();
+    mix.step().unwrap();
+    mix.step().unwrap();
+    assert_eq!(mix.halted, true);
+
+    mix.step_back().unwrap();
+    assert_eq!(mix.halted, false);
+    assert_eq!(mix.pc, 2);
+    assert_eq!(mix.r_a[..], [0, 0, 0, 0, 0, 2]);
+
+    let err = mix.step_back().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::NoHistory);
+}
+
+#[test]
+fn test_history_disabled() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.restart();
+
+    mix.step().unwrap();
+    let err = mix.step_back().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::NoHistory);
+}
+
+#[test]
+fn test_checksum() {
+    let mut mix = MixVM::new();
+    mix.reset();
+    mix.enable_history(1);
+
+    mix.mem[0] = Instruction::new(2000, 5, 0, Opcode::StA).into();
+    mix.r_a = FullWord::from_i64(1).0;
+
+    mix.restart();
+
+    let before = mix.checksum();
+    assert_eq!(before, mix.checksum());
+
+    mix.step().unwrap();
+    let after = mix.checksum();
+    assert_ne!(before, after);
+
+    mix.step_back().unwrap();
+    assert_eq!(mix.checksum(), before);
+
+    mix.step().unwrap();
+    assert_eq!(mix.checksum(), after);
+}
+
+#[test]
+fn test_step_back_uninitialized() {
+    let mut mix = MixVM::new();
+    mix.reset();
+    mix.enable_history(16);
+    mix.strict_uninit = true;
+
+    mix.mem[0] = Instruction::new(2000, 5, 0, Opcode::StA).into();
+    mix.mem[1] = Instruction::new(2000, 5, 0, Opcode::LdA).into();
+
+    mix.restart();
+
+    mix.step().unwrap();
+    assert!(mix.mem.is_initialized(2000));
+
+    mix.step_back().unwrap();
+    assert!(!mix.mem.is_initialized(2000));
+
+    // Skip the store.
+    mix.pc = 1;
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::UninitializedRead);
+}
@@ -275,6 +275,42 @@ fn test_jmp() {
     assert_eq!(mix.r_j[..], [0, 0, 1]);
 }
 
+#[test]
+fn test_jmp_overflow() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(1000, 2, 0, Opcode::Jmp).into(); // JOV 1000
+    mix.mem[1000] = Instruction::new(2000, 2, 0, Opcode::Jmp).into(); // JOV 2000
+    mix.mem[1001] = Instruction::new(3000, 3, 0, Opcode::Jmp).into(); // JNOV 3000
+
+    mix.restart();
+    mix.set_overflow();
+
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 1000);
+    assert_eq!(mix.overflow, false);
+
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 1001);
+    assert_eq!(mix.overflow, false);
+
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 3000);
+    assert_eq!(mix.overflow, false);
+    assert_eq!(mix.r_j[..], [0, 0x03, 0xEA]);
+
+    // JNOV does not jump, but still clears the toggle.
+    mix.mem[3000] = Instruction::new(0, 3, 0, Opcode::Jmp).into();
+    mix.set_overflow();
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 3001);
+    assert_eq!(mix.overflow, false);
+
+    mix.clear_overflow();
+    assert_eq!(mix.overflow, false);
+}
+
 #[test]
 fn test_special() {
     let mut mix = MixVM::new();
@@ -381,6 +417,80 @@ fn test_move() {
     assert_eq!(mix.mem[1002][..], [1, 3, 3, 3, 3, 3]);
 }
 
+#[test]
+fn test_move_r_i1() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(1000, 3, 0, Opcode::Move).into();
+    mix.mem[1] = Instruction::new(1000, 3, 0, Opcode::Move).into();
+
+    mix.r_in[1] = HalfWord::from_i64(2000).0;
+
+    mix.restart();
+
+    mix.step().unwrap();
+    assert_eq!(mix.r_in[1].to_i64().0, 2003);
+    assert_eq!(mix.overflow, false);
+
+    // Only reachable with wrapping targets.
+    mix.address_wrap = true;
+    mix.r_in[1] = HalfWord::from_i64(0xFFFE).0;
+
+    mix.step().unwrap();
+    assert_eq!(mix.r_in[1][..], [0, 0x00, 0x01]);
+    assert_eq!(mix.overflow, true);
+}
+
+#[test]
+fn test_move_zero() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(1000, 0, 0, Opcode::Move).into();
+    mix.mem[1000].set_all([0, 1, 2, 3, 4, 5]);
+    mix.r_in[1].set_all([1, 0, 0]);
+
+    mix.restart();
+
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 1);
+    assert_eq!(mix.overflow, false);
+    assert_eq!(mix.r_in[1][..], [1, 0, 0]);
+    assert_eq!(mix.mem[1000][..], [0, 1, 2, 3, 4, 5]);
+    assert_eq!(mix.memory_access_stats().writes, 0);
+}
+
+#[test]
+fn test_move_address_wrap() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(1000, 3, 0, Opcode::Move).into();
+
+    mix.r_in[1] = HalfWord::from_i64(3998).0;
+    mix.mem[1000].set_all([1, 1, 1, 1, 1, 1]);
+    mix.mem[1001].set_all([1, 2, 2, 2, 2, 2]);
+    mix.mem[1002].set_all([1, 3, 3, 3, 3, 3]);
+
+    mix.restart();
+
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::InvalidAddress);
+
+    mix.reset();
+    mix.r_in[1] = HalfWord::from_i64(3998).0;
+    mix.address_wrap = true;
+    mix.restart();
+
+    mix.step().unwrap();
+    assert_eq!(mix.halted, false);
+    assert_eq!(mix.mem[3998][..], [1, 1, 1, 1, 1, 1]);
+    assert_eq!(mix.mem[3999][..], [1, 2, 2, 2, 2, 2]);
+    assert_eq!(mix.mem[0][..], [1, 3, 3, 3, 3, 3]);
+    assert_eq!(mix.r_in[1][..], [0, 0x0F, 0xA1]);
+}
+
 #[test]
 fn test_store_6b() {
     let mut mix = MixVM::new();
@@ -610,6 +720,42 @@ fn test_modify_3b() {
     assert_eq!(mix.r_in[1][..], [0, 0x10, 0xE1]);
 }
 
+#[test]
+fn test_modify_neg_zero() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    // ENTA -0
+    mix.mem[0].set_all([1, 0, 0, 0, 2, Opcode::ModifyA as u8]);
+    // ENNX 0
+    mix.mem[1].set_all([0, 0, 0, 0, 3, Opcode::ModifyX as u8]);
+    // ENT1 -0
+    mix.mem[2].set_all([1, 0, 0, 0, 2, Opcode::Modify1 as u8]);
+    // ENNA -0
+    mix.mem[3].set_all([1, 0, 0, 0, 3, Opcode::ModifyA as u8]);
+    // ENTX -1,1
+    mix.mem[4].set_all([1, 0, 1, 1, 2, Opcode::ModifyX as u8]);
+
+    mix.restart();
+
+    mix.step().unwrap();
+    assert_eq!(mix.r_a[..], [1, 0, 0, 0, 0, 0]);
+
+    mix.step().unwrap();
+    assert_eq!(mix.r_x[..], [1, 0, 0, 0, 0, 0]);
+
+    mix.step().unwrap();
+    assert_eq!(mix.r_in[1][..], [1, 0, 0]);
+
+    mix.step().unwrap();
+    assert_eq!(mix.r_a[..], [0, 0, 0, 0, 0, 0]);
+
+    // rI1 + A is zero, so the sign of the instruction is loaded.
+    mix.r_in[1].set_all([0, 0, 1]);
+    mix.step().unwrap();
+    assert_eq!(mix.r_x[..], [1, 0, 0, 0, 0, 0]);
+}
+
 #[test]
 fn test_add_sub() {
     let mut mix = MixVM::new();
@@ -646,6 +792,41 @@ fn test_add_sub() {
     assert_eq!(mix.r_a[0..=4], [0, 0x02, 0xFE, 0, 0x95]);
 }
 
+#[test]
+fn test_add_sub_arith_mode() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(1000, 5, 0, Opcode::Add).into();
+    mix.mem[1] = Instruction::new(1001, 5, 0, Opcode::Sub).into();
+    mix.mem[1000].set_all([0, 0x80, 0, 0, 0, 0x01]);
+    mix.mem[1001].set_all([0, 0x80, 0, 0, 0, 0x01]);
+
+    mix.r_a.set_all([0, 0x80, 0, 0, 0, 0x02]);
+
+    mix.restart();
+
+    assert_eq!(mix.arith_mode, ArithMode::Modular);
+    mix.step().unwrap();
+    assert_eq!(mix.overflow, true);
+    assert_eq!(mix.r_a[..], [0, 0, 0, 0, 0, 0x03]);
+
+    mix.reset();
+    mix.arith_mode = ArithMode::Saturating;
+    mix.r_a.set_all([0, 0x80, 0, 0, 0, 0x02]);
+
+    mix.step().unwrap();
+    assert_eq!(mix.overflow, true);
+    assert_eq!(mix.r_a[..], [0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+    mix.overflow = false;
+    mix.r_a.set_all([1, 0x80, 0, 0, 0, 0x02]);
+
+    mix.step().unwrap();
+    assert_eq!(mix.overflow, true);
+    assert_eq!(mix.r_a[..], [1, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+}
+
 #[test]
 fn test_mul() {
     let mut mix = MixVM::new();
@@ -800,6 +981,37 @@ fn test_cmp_3b() {
     assert_eq!(mix.comp, CompIndicator::Equal);
 }
 
+#[test]
+fn test_cmp_3b_field() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    // rI1 is compared as if it were [sign, 0, 0, 0, I1, I2].
+    mix.r_in[1].set_all([1, 1, 2]);
+    mix.mem[1000].set_all([0, 9, 9, 9, 1, 2]);
+    mix.mem[1001].set_all([0, 0, 0, 9, 1, 2]);
+
+    // CMP1 1000(4:5)
+    mix.execute(&Instruction::new(1000, 8 * 4 + 5, 0, Opcode::Cmp1))
+        .unwrap();
+    assert_eq!(mix.comp, CompIndicator::Equal);
+
+    // CMP1 1000(1:2)
+    mix.execute(&Instruction::new(1000, 8 * 1 + 2, 0, Opcode::Cmp1))
+        .unwrap();
+    assert_eq!(mix.comp, CompIndicator::Less);
+
+    // CMP1 1001(0:2), where -0 equals +0.
+    mix.execute(&Instruction::new(1001, 2, 0, Opcode::Cmp1))
+        .unwrap();
+    assert_eq!(mix.comp, CompIndicator::Equal);
+
+    // CMP1 1001(0:5)
+    mix.execute(&Instruction::new(1001, 5, 0, Opcode::Cmp1))
+        .unwrap();
+    assert_eq!(mix.comp, CompIndicator::Less);
+}
+
 #[test]
 fn test_jmp_reg_6b() {
     let mut mix = MixVM::new();
@@ -897,3 +1109,23 @@ fn test_shift() {
     assert_eq!(mix.r_a[..], [0, 0, 6, 7, 8, 3]);
     assert_eq!(mix.r_x[..], [1, 4, 0, 0, 5, 0]);
 }
+
+#[test]
+fn test_shift_circular_zero() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(0, 4, 0, Opcode::Shift).into(); // SLC 0
+    mix.mem[1] = Instruction::new(0, 5, 0, Opcode::Shift).into(); // SRC 0
+    mix.mem[2] = Instruction::new(10, 5, 0, Opcode::Shift).into(); // SRC 10
+    mix.r_a.set_all([1, 1, 2, 3, 4, 5]);
+    mix.r_x.set_all([0, 6, 7, 8, 9, 10]);
+
+    mix.restart();
+    for _ in 0..3 {
+        mix.step().unwrap();
+        assert_eq!(mix.halted, false);
+        assert_eq!(mix.r_a[..], [1, 1, 2, 3, 4, 5]);
+        assert_eq!(mix.r_x[..], [0, 6, 7, 8, 9, 10]);
+    }
+}
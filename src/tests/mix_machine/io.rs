@@ -255,3 +255,137 @@ fn test_in_out() {
     mix.step().unwrap();
     assert_eq!(mix.halted, false);
 }
+
+struct BlockIODevice {
+    size: usize,
+    expected: Vec<FullWord>,
+}
+
+impl IODevice for BlockIODevice {
+    fn read(&mut self, buffer: &mut [FullWord]) -> Result<(), ()> {
+        for (i, word) in buffer.iter_mut().enumerate() {
+            *word = FullWord::from_i64(i as i64 + 1).0;
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, data: &[FullWord]) -> Result<(), usize> {
+        assert_eq!(data, &self.expected[..]);
+        Ok(())
+    }
+
+    fn control(&mut self, _: i16) -> Result<(), ()> {
+        unimplemented!()
+    }
+
+    fn is_busy(&self) -> Result<bool, ()> {
+        unimplemented!()
+    }
+
+    fn is_ready(&self) -> Result<bool, ()> {
+        unimplemented!()
+    }
+
+    fn get_block_size(&self) -> usize {
+        self.size
+    }
+}
+
+#[test]
+fn test_in_out_end_of_mem() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    let expected = (1..=16).map(|x| FullWord::from_i64(x).0).collect();
+    mix.io_devices[0] = Some(Box::new(BlockIODevice { size: 16, expected }));
+
+    mix.mem[0] = Instruction::new(3984, 0, 0, Opcode::In).into();
+    mix.mem[1] = Instruction::new(3984, 0, 0, Opcode::Out).into();
+    mix.mem[2] = Instruction::new(3985, 0, 0, Opcode::In).into();
+
+    mix.restart();
+
+    mix.step().unwrap();
+    assert_eq!(mix.mem[3984].to_i64().0, 1);
+    assert_eq!(mix.mem[3999].to_i64().0, 16);
+
+    mix.step().unwrap();
+
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::InvalidAddress);
+}
+
+#[test]
+fn test_in_out_address_wrap() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    let expected = (1..=4).map(|x| FullWord::from_i64(x).0).collect();
+    mix.io_devices[0] = Some(Box::new(BlockIODevice { size: 4, expected }));
+
+    mix.mem[100] = Instruction::new(3998, 0, 0, Opcode::In).into();
+    mix.mem[101] = Instruction::new(3998, 0, 0, Opcode::Out).into();
+    mix.mem[102] = Instruction::new(3999, 0, 0, Opcode::Out).into();
+
+    mix.pc = 100;
+    mix.restart();
+
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::InvalidAddress);
+    assert_eq!(mix.mem[3998].to_i64().0, 0);
+
+    mix.pc = 100;
+    mix.address_wrap = true;
+    mix.recording = true;
+    mix.enable_history(1);
+    mix.restart();
+
+    mix.step().unwrap();
+    assert_eq!(mix.mem[3998].to_i64().0, 1);
+    assert_eq!(mix.mem[3999].to_i64().0, 2);
+    assert_eq!(mix.mem[0].to_i64().0, 3);
+    assert_eq!(mix.mem[1].to_i64().0, 4);
+
+    mix.step_back().unwrap();
+    assert_eq!(mix.pc, 100);
+    assert_eq!(mix.mem[3998].to_i64().0, 0);
+    assert_eq!(mix.mem[0].to_i64().0, 0);
+
+    mix.events.clear();
+    mix.step().unwrap();
+    mix.step().unwrap();
+    assert_eq!(
+        mix.events,
+        [
+            MachineEvent::DeviceIO {
+                unit: 0,
+                opcode: Opcode::In
+            },
+            MachineEvent::MemoryWrite {
+                addr: 3998,
+                value: FullWord::from_i64(1).0
+            },
+            MachineEvent::MemoryWrite {
+                addr: 3999,
+                value: FullWord::from_i64(2).0
+            },
+            MachineEvent::MemoryWrite {
+                addr: 0,
+                value: FullWord::from_i64(3).0
+            },
+            MachineEvent::MemoryWrite {
+                addr: 1,
+                value: FullWord::from_i64(4).0
+            },
+            MachineEvent::DeviceIO {
+                unit: 0,
+                opcode: Opcode::Out
+            },
+        ]
+    );
+
+    // Location 2 has never been written.
+    mix.strict_uninit = true;
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::UninitializedRead);
+}
@@ -1,8 +1,16 @@
+use crate::*;
+
 mod comp;
 mod error;
 mod instr;
 mod operation;
 
+#[cfg(feature = "std")]
+mod events;
+
+#[cfg(feature = "std")]
+mod history;
+
 #[cfg(feature = "io")]
 mod io;
 
@@ -14,3 +22,29 @@ mod x_binary;
 
 #[cfg(feature = "x-binarith")]
 mod x_binarith;
+
+/// Knuth's program M at `base` to `base + 8`, finding the maximum of
+/// `X[1..n]` with `X` at `1000` and `n` in `rI1`, followed by
+/// `X[1..5] = 3, 9, 2, 9, 4`.
+///
+/// With `n = 5`, it halts after 25 steps with `rA = 9` and `rI2 = 4`.
+fn maximum_program(base: u16) -> [(u16, FullWord); 14] {
+    let b = base as i16;
+    let x = |i: u16, value: i64| (1000 + i, FullWord::from_i64(value).0);
+    [
+        (base, Instruction::new(0, 2, 1, Opcode::Modify3).into()), // ENT3 0,1
+        (base + 1, Instruction::new(b + 4, 0, 0, Opcode::Jmp).into()), // JMP CHANGEM
+        (base + 2, Instruction::new(1000, 5, 3, Opcode::CmpA).into()), // CMPA X,3
+        (base + 3, Instruction::new(b + 6, 7, 0, Opcode::Jmp).into()), // JGE *+3
+        (base + 4, Instruction::new(0, 2, 3, Opcode::Modify2).into()), // ENT2 0,3
+        (base + 5, Instruction::new(1000, 5, 3, Opcode::LdA).into()), // LDA X,3
+        (base + 6, Instruction::new(1, 1, 0, Opcode::Modify3).into()), // DEC3 1
+        (base + 7, Instruction::new(b + 2, 2, 0, Opcode::J3).into()), // J3P LOOP
+        (base + 8, Instruction::new(0, 2, 0, Opcode::Special).into()), // HLT
+        x(1, 3),
+        x(2, 9),
+        x(3, 2),
+        x(4, 9),
+        x(5, 4),
+    ]
+}
@@ -1,4 +1,4 @@
-use crate::mix_vm::*;
+use crate::*;
 
 #[test]
 fn test_reset_restart() {
@@ -20,3 +20,727 @@ fn test_reset_restart() {
     assert_eq!(mix.pc, 0);
     assert_eq!(mix.overflow, false);
 }
+
+#[test]
+fn test_reset_and_run() {
+    let mut mix = MixVM::new();
+
+    mix.halted = true;
+    mix.pc = 123;
+
+    mix.reset();
+    assert_eq!(mix.halted, true);
+    assert_eq!(mix.pc, 0);
+
+    mix.pc = 123;
+
+    mix.reset_and_run();
+    assert_eq!(mix.halted, false);
+    assert_eq!(mix.pc, 0);
+}
+
+#[test]
+fn test_is_running() {
+    let mut mix = MixVM::new();
+    mix.reset();
+    assert_eq!(mix.is_running(), false);
+
+    mix.mem[0] = Instruction::new(0, 2, 0, Opcode::Special).into();
+
+    mix.restart();
+    assert_eq!(mix.is_running(), true);
+
+    mix.halt();
+    assert_eq!(mix.is_running(), false);
+
+    mix.restart();
+    mix.step().unwrap();
+    assert_eq!(mix.is_running(), false);
+}
+
+#[test]
+fn test_registers() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(2000, 5, 0, Opcode::LdA).into();
+    mix.mem[1] = Instruction::new(2000, 5, 0, Opcode::Ld2N).into();
+    mix.mem[2] = Instruction::new(2000, 5, 0, Opcode::CmpA).into();
+    mix.mem[2000].set_all([1, 0, 0, 0, 1, 2]);
+
+    mix.restart();
+
+    mix.step().unwrap();
+    mix.step().unwrap();
+    mix.step().unwrap();
+
+    let mut expected = Registers {
+        r_a: FullWord::from_bytes([1, 0, 0, 0, 1, 2]),
+        comp: CompIndicator::Equal,
+        ..Default::default()
+    };
+    expected.r_in[2] = HalfWord::from_bytes([0, 1, 2]);
+    assert_eq!(mix.registers(), expected);
+
+    mix.overflow = true;
+    assert_ne!(mix.registers(), expected);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_dump_state() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.r_a = FullWord::from_i64(-0x0102).0;
+    mix.r_x = FullWord::from_bytes([0, 0, 0, 0, 1, 200]);
+    mix.r_in[3] = HalfWord::from_i64(7).0;
+    mix.pc = 123;
+    mix.overflow = true;
+
+    let dump = mix.dump_state();
+    assert!(dump.contains("rA:  - 000 000 000 001 002"));
+    assert!(dump.contains("rX:  + 000 000 000 001 200"));
+    assert!(dump.contains("rI3: + 000 007"));
+    assert!(dump.contains("OV:  ON"));
+    assert!(dump.contains("CI:  Equal"));
+    assert!(dump.contains("PC:  0123"));
+}
+
+#[test]
+fn test_peek_poke() {
+    let mut mix = MixVM::new();
+
+    mix.poke_i64(100, -42).unwrap();
+    assert_eq!(mix.mem[100][..], [1, 0, 0, 0, 0, 42]);
+    assert_eq!(mix.peek_i64(100), Some(-42));
+
+    assert_eq!(mix.poke_i64(101, 1 << 40), Err(ErrorCode::Overflow));
+    assert_eq!(mix.peek_i64(101), Some(0));
+
+    assert_eq!(mix.poke_i64(4000, 1), Err(ErrorCode::InvalidAddress));
+    assert_eq!(mix.peek_i64(4000), None);
+}
+
+#[test]
+fn test_current_word() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(2000, 5, 0, Opcode::LdA).into();
+    mix.mem[1] = FullWord::from_bytes([1, 2, 3, 4, 5, 200]);
+    assert_eq!(mix.current_word(), Some(mix.mem[0]));
+
+    mix.pc = 1;
+    assert_eq!(mix.current_word(), Some(mix.mem[1]));
+    assert_eq!(mix.word_at(1), mix.current_word());
+
+    mix.pc = 4000;
+    assert_eq!(mix.current_word(), None);
+}
+
+#[test]
+fn test_read_field() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[2000] = FullWord::from_bytes([1, 1, 2, 3, 4, 5]);
+    mix.execute(&Instruction::new(2000, 8 + 3, 0, Opcode::LdA))
+        .unwrap();
+    assert_eq!(mix.read_field(2000, 8 + 3), Ok(mix.r_a.to_i64().0));
+    assert_eq!(mix.read_field(2000, 8 + 3), Ok(0x010203));
+    assert_eq!(mix.read_field(2000, 3), Ok(-0x010203));
+
+    assert_eq!(mix.read_field(4000, 5), Err(ErrorCode::InvalidAddress));
+    assert_eq!(mix.read_field(2000, 6), Err(ErrorCode::InvalidField));
+}
+
+#[test]
+fn test_poke_instruction() {
+    let mut mix = MixVM::new();
+    let jmp = Instruction::new(-100, 0, 2, Opcode::Jmp);
+
+    mix.poke_instruction(0, jmp).unwrap();
+    assert_eq!(mix.mem[0].as_instruction(), Ok(jmp));
+    assert_eq!(
+        mix.poke_instruction(4000, jmp),
+        Err(ErrorCode::InvalidAddress)
+    );
+
+    mix.reset_and_run();
+    mix.r_in[2] = HalfWord::from_i64(300).0;
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 200);
+}
+
+#[test]
+fn test_halt_reason() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(1000, 5, 0, Opcode::Add).into();
+    mix.mem[1] = Instruction::new(0, 2, 0, Opcode::Special).into();
+    mix.mem[2] = Instruction::new(0, 2, 0, Opcode::Special).into();
+    mix.mem[3] = Instruction::new(0, 63, 0, Opcode::Special).into();
+    mix.mem[1000] = FullWord::from_i64(1).0;
+    mix.r_a = FullWord::from_i64(0xFF_FFFF_FFFF).0;
+
+    mix.restart();
+    assert_eq!(mix.halt_reason, None);
+
+    mix.step().unwrap();
+    assert_eq!(mix.overflow, true);
+    mix.step().unwrap();
+    assert_eq!(mix.halted, true);
+    assert_eq!(mix.halt_reason, Some(HaltReason::Hlt));
+
+    mix.warn_overflow_on_halt = true;
+    mix.restart();
+    assert_eq!(mix.halt_reason, None);
+
+    mix.step().unwrap();
+    assert_eq!(mix.halted, true);
+    assert_eq!(mix.halt_reason, Some(HaltReason::HltWithOverflow));
+
+    mix.restart();
+
+    let err = mix.step().expect_err("Expect error");
+    assert_eq!(err, ErrorCode::InvalidField);
+    assert_eq!(
+        mix.halt_reason,
+        Some(HaltReason::Error(ErrorCode::InvalidField))
+    );
+}
+
+#[test]
+fn test_load_program_array() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    let program: [FullWord; 3] = [
+        Instruction::new(1000, 5, 0, Opcode::LdA).into(),
+        Instruction::new(1000, 5, 0, Opcode::Add).into(),
+        Instruction::new(0, 2, 0, Opcode::Special).into(),
+    ];
+    mix.load_program_array(0, program).unwrap();
+    mix.load_program_array(1000, [FullWord::from_i64(21).0])
+        .unwrap();
+
+    mix.restart();
+
+    mix.step().unwrap();
+    mix.step().unwrap();
+    mix.step().unwrap();
+    assert_eq!(mix.halted, true);
+    assert_eq!(mix.r_a.to_i64().0, 42);
+
+    let err = mix
+        .load_program_array(3998, program)
+        .expect_err("Expect error");
+    assert_eq!(err, ErrorCode::InvalidAddress);
+    assert_eq!(mix.mem[3998][..], [0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_execute() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[2000] = FullWord::from_i64(-42).0;
+    mix.pc = 100;
+
+    mix.execute(&Instruction::new(2000, 5, 0, Opcode::LdA))
+        .unwrap();
+    assert_eq!(mix.r_a.to_i64().0, -42);
+    assert_eq!(mix.pc, 100);
+    assert_eq!(mix.halted, true);
+
+    mix.execute(&Instruction::new(101, 0, 0, Opcode::Jmp))
+        .unwrap();
+    assert_eq!(mix.pc, 101);
+    assert_eq!(mix.r_j.to_i64().0, 101);
+
+    mix.execute(&Instruction::new(300, 1, 0, Opcode::JA))
+        .unwrap();
+    assert_eq!(mix.pc, 101);
+
+    let err = mix
+        .execute(&Instruction::new(2000, 7, 0, Opcode::Ld1))
+        .expect_err("Expect error");
+    assert_eq!(err, ErrorCode::InvalidField);
+    assert_eq!(mix.pc, 101);
+}
+
+#[test]
+fn test_execute_pc_max() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[2000] = FullWord::from_i64(42).0;
+    mix.pc = u16::MAX;
+
+    mix.execute(&Instruction::new(2000, 5, 0, Opcode::LdA))
+        .unwrap();
+    assert_eq!(mix.r_a.to_i64().0, 42);
+    assert_eq!(mix.pc, u16::MAX);
+
+    mix.execute(&Instruction::new(100, 0, 0, Opcode::Jmp))
+        .unwrap();
+    assert_eq!(mix.pc, 100);
+    assert_eq!(mix.r_j.to_i64().0, 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_trap() {
+    use std::boxed::Box;
+
+    let mut mix = MixVM::new();
+
+    // TRAP 100,1; HLT
+    mix.mem[0] = Instruction::new(100, MixVM::TRAP_FIELD, 1, Opcode::Special).into();
+    mix.mem[1] = Instruction::new(0, 2, 0, Opcode::Special).into();
+
+    mix.set_trap_handler(Box::new(|mix, m| {
+        mix.r_x = FullWord::from_i64(m as i64).0;
+        Ok(())
+    }));
+    mix.reset_and_run();
+    mix.r_in[1] = HalfWord::from_i64(23).0;
+    mix.step().unwrap();
+    assert_eq!(mix.r_x.to_i64().0, 123);
+    assert_eq!(mix.pc, 1);
+    mix.step().unwrap();
+    assert_eq!(mix.halt_reason, Some(HaltReason::Hlt));
+
+    mix.set_trap_handler(Box::new(|_, _| Err(ErrorCode::IOError)));
+    mix.reset_and_run();
+    assert_eq!(mix.step(), Err(ErrorCode::IOError));
+    assert_eq!(mix.halted, true);
+}
+
+#[test]
+fn test_trap_without_handler() {
+    let mut mix = MixVM::new();
+    let err = mix
+        .execute(&Instruction::new(0, MixVM::TRAP_FIELD, 0, Opcode::Special))
+        .expect_err("Expect error");
+    assert_eq!(err, ErrorCode::InvalidField);
+}
+
+#[test]
+fn test_step_skipping_nops() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    // 100 NOPs, LDA 2000, NOP, HLT
+    mix.mem[100] = Instruction::new(2000, 5, 0, Opcode::LdA).into();
+    mix.mem[101] = Instruction::new(0, 0, 0, Opcode::Nop).into();
+    mix.mem[102] = Instruction::new(0, 2, 0, Opcode::Special).into();
+    mix.mem[2000] = FullWord::from_i64(7).0;
+    mix.restart();
+
+    mix.step_skipping_nops().unwrap();
+    assert_eq!(mix.pc, 100);
+    assert_eq!(mix.r_a.to_i64().0, 0);
+
+    mix.step_skipping_nops().unwrap();
+    assert_eq!(mix.pc, 101);
+    assert_eq!(mix.r_a.to_i64().0, 7);
+
+    mix.step_skipping_nops().unwrap();
+    assert_eq!(mix.pc, 102);
+
+    mix.step_skipping_nops().unwrap();
+    assert_eq!(mix.halted, true);
+}
+
+#[test]
+fn test_step_skipping_nops_pc_wrap() {
+    let mut mix = MixVM::new();
+    mix.pc_wrap = true;
+    mix.reset_and_run();
+
+    // Blank memory is all NOPs.
+    mix.step_skipping_nops().unwrap();
+    assert_eq!(mix.pc, 0);
+    assert!(mix.is_running());
+}
+
+#[test]
+fn test_memory_access_stats() {
+    let mut mix = MixVM::new();
+
+    // Copy 3 words from 1000 to 2000 in a loop.
+    mix.mem[0] = Instruction::new(2, 2, 0, Opcode::Modify1).into(); // ENT1 2
+    mix.mem[1] = Instruction::new(1000, 5, 1, Opcode::LdA).into(); // LDA 1000,1
+    mix.mem[2] = Instruction::new(2000, 5, 1, Opcode::StA).into(); // STA 2000,1
+    mix.mem[3] = Instruction::new(1, 1, 0, Opcode::Modify1).into(); // DEC1 1
+    mix.mem[4] = Instruction::new(1, 3, 0, Opcode::J1).into(); // J1NN 1
+    mix.mem[5] = Instruction::new(0, 2, 0, Opcode::Special).into(); // HLT
+    mix.mem[1002] = FullWord::from_i64(42).0;
+    mix.reset_and_run();
+    while mix.is_running() {
+        mix.step().unwrap();
+    }
+    assert_eq!(mix.mem[2002].to_i64().0, 42);
+
+    // 1 + 4 * 3 + 1 fetches, and 3 loads.
+    let expected = MemAccessStats {
+        reads: 17,
+        writes: 3,
+    };
+    assert_eq!(mix.memory_access_stats(), expected);
+
+    mix.reset();
+    assert_eq!(mix.memory_access_stats(), MemAccessStats::default());
+}
+
+#[test]
+fn test_with_memory_image() {
+    let mut image = std::vec![0; 6 * Mem::SIZE];
+    let hlt: FullWord = Instruction::new(0, 2, 0, Opcode::Special).into();
+    image[6..12].copy_from_slice(&hlt[..]);
+
+    let mut mix = MixVM::with_memory_image(&image).unwrap();
+    assert_eq!(mix.mem[1], hlt);
+    assert!(mix.mem.is_initialized(3999));
+
+    mix.restart();
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 1);
+    mix.step().unwrap();
+    assert_eq!(mix.halt_reason, Some(HaltReason::Hlt));
+
+    assert!(MixVM::with_memory_image(&image[..6 * Mem::SIZE - 1]).is_err());
+    image[6 * 3999] = 2;
+    assert!(MixVM::with_memory_image(&image).is_err());
+}
+
+#[test]
+fn test_run_until_halt() {
+    let mut mix = MixVM::new();
+    mix.mem[10] = Instruction::new(0, 2, 0, Opcode::Special).into();
+    mix.mem[11] = Instruction::new(0, 0, 0, Opcode::Div).into();
+    mix.reset_and_run();
+
+    assert_eq!(
+        mix.run_until_halt(),
+        Ok(RunReport {
+            steps: 11,
+            stop: StopReason::Halted
+        })
+    );
+    assert_eq!(mix.pc, 11);
+
+    // Running off the end of memory is an error.
+    mix.restart();
+    assert_eq!(mix.run_until_halt(), Err(ErrorCode::InvalidAddress));
+    assert_eq!(mix.overflow, true);
+    assert_eq!(mix.fault_pc, Some(4000));
+}
+
+#[test]
+fn test_run_until_halt_maximum() {
+    let mut mix = MixVM::new();
+    for (addr, word) in super::maximum_program(0) {
+        mix.mem[addr] = word;
+    }
+    mix.reset_and_run();
+    mix.r_in[1] = HalfWord::from_i64(5).0;
+
+    let report = mix.run_until_halt().unwrap();
+    assert_eq!(report.steps, 25);
+    assert_eq!(report.stop, StopReason::Halted);
+    assert_eq!(mix.r_a.to_i64().0, 9);
+    assert_eq!(mix.r_in[2].to_i64().0, 4);
+}
+
+#[test]
+fn test_run_program() {
+    let mut program = super::maximum_program(100).to_vec();
+    program.push((99, Instruction::new(5, 2, 0, Opcode::Modify1).into())); // ENT1 5
+
+    let mut mix = MixVM::new();
+    mix.mem[2000] = FullWord::from_i64(1).0;
+    let report = mix.run_program(&program, 99, 100).unwrap();
+    assert_eq!(report.steps, 26);
+    assert_eq!(report.stop, StopReason::Halted);
+    assert_eq!(mix.r_a.to_i64().0, 9);
+    assert_eq!(mix.r_in[2].to_i64().0, 4);
+    assert_eq!(mix.mem[2000].to_i64().0, 0);
+
+    let report = mix.run_program(&program, 99, 10).unwrap();
+    assert_eq!(report.steps, 10);
+    assert_eq!(report.stop, StopReason::StepLimit);
+    assert!(mix.is_running());
+
+    assert_eq!(
+        mix.run_program(&[(4000, FullWord::new())], 0, 10),
+        Err(ErrorCode::InvalidAddress)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_run_until_halt_interrupted() {
+    use std::sync::atomic::Ordering;
+    use std::thread;
+    use std::time::Duration;
+
+    let mut mix = MixVM::new();
+    // JMP 0
+    mix.mem[0] = Instruction::new(0, 0, 0, Opcode::Jmp).into();
+    mix.reset_and_run();
+
+    let handle = mix.interrupt_handle();
+    let interrupter = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        handle.store(true, Ordering::Relaxed);
+    });
+    assert_eq!(mix.run_until_halt().unwrap().stop, StopReason::Interrupted);
+    interrupter.join().unwrap();
+    assert!(mix.is_running());
+
+    mix.interrupt();
+    assert_eq!(
+        mix.run_until_halt(),
+        Ok(RunReport {
+            steps: 0,
+            stop: StopReason::Interrupted
+        })
+    );
+    mix.mem[0] = Instruction::new(0, 2, 0, Opcode::Special).into();
+    assert_eq!(
+        mix.run_until_halt(),
+        Ok(RunReport {
+            steps: 1,
+            stop: StopReason::Halted
+        })
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_validate_program() {
+    let mut mix = MixVM::new();
+
+    mix.mem[0] = Instruction::new(2000, 5, 0, Opcode::LdA).into();
+    mix.mem[1] = FullWord::from_bytes([0, 0, 0, 0, 0, 64]);
+    mix.mem[2] = Instruction::new(-1, 0, 1, Opcode::Jmp).into();
+    mix.mem[3] = Instruction::new(4000, 0, 0, Opcode::Jmp).into();
+    mix.mem[4] = Instruction::new(2000, 2, 0, Opcode::Special).into();
+    mix.mem[5] = Instruction::new(2000, 8 * 3 + 1, 0, Opcode::CmpA).into();
+
+    let warnings = mix.validate_program(0..6);
+    assert_eq!(
+        warnings,
+        [
+            ProgramWarning {
+                addr: 1,
+                error: ErrorCode::IllegalInstruction
+            },
+            ProgramWarning {
+                addr: 3,
+                error: ErrorCode::InvalidAddress
+            },
+            ProgramWarning {
+                addr: 5,
+                error: ErrorCode::InvalidField
+            },
+        ]
+    );
+
+    assert_eq!(mix.validate_program(3990..5000).len(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_diff() {
+    use std::vec;
+
+    let mut left = MixVM::new();
+    let mut right = MixVM::new();
+    assert!(left.diff(&right).is_empty());
+
+    left.r_a = FullWord::from_i64(42).0;
+    right.mem[2000] = FullWord::from_i64(-1).0;
+    assert_eq!(
+        left.diff(&right),
+        vec![
+            StateDifference::Register {
+                register: Register::A,
+                left: FullWord::from_i64(42).0,
+                right: FullWord::new(),
+            },
+            StateDifference::Memory {
+                addr: 2000,
+                left: FullWord::new(),
+                right: FullWord::from_i64(-1).0,
+            },
+        ]
+    );
+
+    right.r_in[3] = HalfWord::from_i64(-7).0;
+    assert!(left.diff(&right).contains(&StateDifference::Register {
+        register: Register::I3,
+        left: FullWord::new(),
+        right: FullWord::from_i64(-7).0,
+    }));
+}
+
+#[test]
+fn test_pc_wrap() {
+    let mut mix = MixVM::new();
+    mix.mem[0] = Instruction::new(0, 2, 0, Opcode::Special).into(); // HLT
+    mix.mem[3999] = Instruction::new(1, 2, 0, Opcode::ModifyA).into(); // ENTA 1
+
+    mix.restart();
+    mix.pc = 3999;
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 4000);
+    assert_eq!(mix.step(), Err(ErrorCode::InvalidAddress));
+    assert_eq!(mix.fault_pc, Some(4000));
+
+    mix.pc_wrap = true;
+    mix.restart();
+    mix.pc = 3999;
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 0);
+    assert_eq!(mix.r_a.to_i64().0, 1);
+    mix.step().unwrap();
+    assert_eq!(mix.halt_reason, Some(HaltReason::Hlt));
+}
+
+#[test]
+fn test_memory_region_hash() {
+    let mut mix = MixVM::new();
+    mix.mem[0] = Instruction::new(3000, 5, 0, Opcode::StA).into();
+    mix.mem[1] = Instruction::new(2000, 5, 0, Opcode::StA).into();
+    mix.mem[2] = Instruction::new(2999, 5, 0, Opcode::StA).into();
+    mix.reset_and_run();
+    mix.r_a = FullWord::from_i64(-42).0;
+
+    let hash = mix.memory_region_hash(2000..3000);
+    mix.step().unwrap();
+    assert_eq!(mix.memory_region_hash(2000..3000), hash);
+    mix.step().unwrap();
+    let hash_2 = mix.memory_region_hash(2000..3000);
+    assert_ne!(hash_2, hash);
+    mix.step().unwrap();
+    assert_ne!(mix.memory_region_hash(2000..3000), hash_2);
+
+    assert_eq!(
+        mix.memory_region_hash(3990..5000),
+        mix.memory_region_hash(3990..4000)
+    );
+}
+
+#[test]
+fn test_last_executed() {
+    let mut mix = MixVM::new();
+    mix.mem[0] = Instruction::new(5, 0, 0, Opcode::Jmp).into();
+    mix.mem[5] = Instruction::new(42, 2, 0, Opcode::ModifyA).into();
+    mix.reset_and_run();
+    assert_eq!(mix.last_executed, None);
+
+    mix.step().unwrap();
+    assert_eq!(
+        mix.last_executed,
+        Some((0, Instruction::new(5, 0, 0, Opcode::Jmp)))
+    );
+    mix.step().unwrap();
+    assert_eq!(
+        mix.last_executed,
+        Some((5, Instruction::new(42, 2, 0, Opcode::ModifyA)))
+    );
+
+    mix.reset();
+    assert_eq!(mix.last_executed, None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_warnings() {
+    let mut mix = MixVM::new();
+    mix.mem[0] = Instruction::new(2000, 5, 0, Opcode::LdA).into(); // LDA 2000
+    mix.mem[1] = Instruction::new(2001, 5, 0, Opcode::Ld1).into(); // LD1 2001
+    mix.mem[2] = Instruction::new(2001, 0, 0, Opcode::Jmp).into(); // JMP 2001
+    mix.mem[2001] = FullWord::from_bytes([0, 0, 0, 1, 0, 255]);
+    mix.reset_and_run();
+
+    // Nothing is collected by default.
+    mix.step().unwrap();
+    assert!(mix.warnings.is_empty());
+
+    mix.reset_and_run();
+    mix.collect_warnings = true;
+    mix.step().unwrap();
+    assert_eq!(
+        mix.warnings,
+        [RuntimeWarning::UninitializedRead { pc: 0, addr: 2000 }]
+    );
+    mix.step().unwrap();
+    assert_eq!(mix.r_in[1][..], [0, 0, 255]);
+    assert_eq!(
+        mix.warnings[1],
+        RuntimeWarning::IndexTruncated { pc: 1, index: 1 }
+    );
+    mix.step().unwrap();
+    assert_eq!(
+        mix.warnings[2],
+        RuntimeWarning::JumpToData { pc: 2, to: 2001 }
+    );
+    assert_eq!(mix.warnings.len(), 3);
+    assert!(mix.is_running());
+
+    mix.reset();
+    assert!(mix.warnings.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_step_with_delta() {
+    use std::vec;
+
+    let mut mix = MixVM::new();
+    mix.mem[0] = Instruction::new(1000, 5, 0, Opcode::Add).into();
+    mix.mem[1] = Instruction::new(1001, 5, 0, Opcode::StA).into();
+    mix.mem[1000] = FullWord::from_bytes([0, 0x80, 0, 0, 0, 1]);
+    mix.reset_and_run();
+    mix.r_a = FullWord::from_bytes([0, 0x80, 0, 0, 0, 2]);
+
+    let delta = mix.step_with_delta().unwrap();
+    assert_eq!(
+        delta,
+        StepDelta {
+            pc: 0,
+            changes: vec![
+                StateDifference::Register {
+                    register: Register::A,
+                    left: FullWord::from_bytes([0, 0x80, 0, 0, 0, 2]),
+                    right: FullWord::from_bytes([0, 0, 0, 0, 0, 3]),
+                },
+                StateDifference::Overflow {
+                    left: false,
+                    right: true,
+                },
+                StateDifference::Pc { left: 0, right: 1 },
+            ],
+        }
+    );
+
+    let delta = mix.step_with_delta().unwrap();
+    assert_eq!(delta.pc, 1);
+    assert_eq!(
+        delta.changes,
+        [
+            StateDifference::Pc { left: 1, right: 2 },
+            StateDifference::Memory {
+                addr: 1001,
+                left: FullWord::new(),
+                right: FullWord::from_i64(3).0,
+            },
+        ]
+    );
+}
@@ -29,6 +29,37 @@ fn test_binary_jmp_reg_6b() {
     assert_eq!(mix.r_j[..], [0, 0x03, 0xEA]);
 }
 
+#[test]
+fn test_binary_jae_jao() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    // JAE, JAO, JAE, JAO
+    mix.mem[0] = Instruction::new(1000, 6, 0, Opcode::JA).into();
+    mix.mem[1] = Instruction::new(1000, 7, 0, Opcode::JA).into();
+    mix.mem[1000] = Instruction::new(2000, 6, 0, Opcode::JA).into();
+    mix.mem[2000] = Instruction::new(3000, 7, 0, Opcode::JA).into();
+
+    // Odd magnitudes are odd regardless of the sign.
+    mix.r_a = FullWord::from_i64(-3).0;
+
+    mix.restart();
+
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 1);
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 1000);
+
+    // Even magnitudes, including zeros, are even.
+    mix.r_a = FullWord::from_i64(-0x0100).0;
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 2000);
+    mix.r_a.set_all([1, 0, 0, 0, 0, 0]);
+    mix.step().unwrap();
+    assert_eq!(mix.pc, 2001);
+    assert_eq!(mix.halted, false);
+}
+
 #[test]
 fn test_binary_shift() {
     let mut mix = MixVM::new();
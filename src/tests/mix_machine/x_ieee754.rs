@@ -303,3 +303,43 @@ fn test_ieee754_convert() {
         assert_eq!(mix.r_a[..], [0, 0, bytes[0], bytes[1], bytes[2], bytes[3]]);
     }
 }
+
+#[test]
+fn test_ieee754_convert_round() {
+    let mut mix = MixVM::new();
+    mix.reset();
+
+    mix.mem[0] = Instruction::new(1000, 5, 0, Opcode::LdA).into();
+    mix.mem[1] = Instruction::new(0, 3, 0, Opcode::Special).into();
+
+    for (value, expected) in [
+        (2.5f32, 2i64),
+        (3.5, 4),
+        (-2.5, -2),
+        (-3.5, -4),
+        (2.4999, 2),
+        (2.5001, 3),
+        (0.5, 0),
+        (-1.5, -2),
+    ] {
+        let bytes = value.to_be_bytes();
+        mix.mem[1000].set_all([0, 0, bytes[0], bytes[1], bytes[2], bytes[3]]);
+        mix.restart();
+        mix.pc = 0;
+        mix.step().unwrap();
+        mix.step().unwrap();
+        assert_eq!(mix.r_a.to_i64().0, expected, "{}", value);
+        assert_eq!(mix.overflow, false);
+    }
+
+    // The integer part does not fit in 1 byte.
+    mix.mem[1] = Instruction::new(0, 5, 0, Opcode::Special).into();
+    let bytes = 127.5f32.to_be_bytes();
+    mix.mem[1000].set_all([0, 0, bytes[0], bytes[1], bytes[2], bytes[3]]);
+    mix.restart();
+    mix.pc = 0;
+    mix.step().unwrap();
+    mix.step().unwrap();
+    assert_eq!(mix.r_a[..], [0, 0, 0, 0, 0, 128]);
+    assert_eq!(mix.overflow, true);
+}
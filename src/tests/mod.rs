@@ -5,3 +5,6 @@ mod alphabet;
 mod instr;
 mod mem;
 mod mix_machine;
+
+#[cfg(feature = "io")]
+mod devices;
@@ -2,11 +2,8 @@
 #![allow(clippy::all)]
 #![allow(clippy::unwrap_used)]
 
-use std::sync::RwLock;
-
 use mixture::*;
 
-static PRIMES_OUTPUT: RwLock<String> = RwLock::new(String::new());
 const PRIMES_OUTPUT_EXPECTED: &'static str = r#"FIRST|FIVE|HUNDRED|PRIMES|||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||
 |||||0000|0233|0547|0877|1229|1597|1993|2371|2749|3187||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||
 |||||0003|0239|0557|0881|1231|1601|1997|2377|2753|3191||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||
@@ -60,7 +57,9 @@ const PRIMES_OUTPUT_EXPECTED: &'static str = r#"FIRST|FIVE|HUNDRED|PRIMES|||||||
 |||||0229|0541|0863|1223|1583|1987|2357|2741|3181|3571||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||||
 "#;
 
-struct LineCollectorIODevice {}
+struct LineCollectorIODevice {
+    output: String,
+}
 
 impl IODevice for LineCollectorIODevice {
     fn read(&mut self, _: &mut [FullWord]) -> Result<(), ()> {
@@ -69,7 +68,7 @@ impl IODevice for LineCollectorIODevice {
 
     fn write(&mut self, data: &[FullWord]) -> Result<(), usize> {
         assert_eq!(data.len(), self.get_block_size());
-        let mut writer = PRIMES_OUTPUT.write().unwrap();
+        let writer = &mut self.output;
         let mut count_written: usize = 0;
         // For each word...
         for word in data {
@@ -81,14 +80,14 @@ impl IODevice for LineCollectorIODevice {
                     .try_into()
                     .map_err(|_| count_written)?;
                 if ch != ' ' {
-                    *writer += format!("{}", ch).as_str();
+                    writer.push(ch);
                 } else {
-                    *writer += "|";
+                    writer.push('|');
                 }
                 count_written += 1;
             }
         }
-        *writer += "\n";
+        writer.push('\n');
         Ok(())
     }
 
@@ -117,7 +116,9 @@ fn primes() {
     let mut mix = MixVM::new();
     mix.reset();
 
-    mix.io_devices[18] = Some(Box::new(LineCollectorIODevice {}));
+    mix.io_devices[18] = Some(Box::new(LineCollectorIODevice {
+        output: String::new(),
+    }));
 
     // Test sequence: D. E. Knuth, 'The Art of Computer Programming',
     // Volume 1, pp 148.
@@ -171,6 +172,6 @@ fn primes() {
         mix.step().unwrap();
     }
 
-    let reader = PRIMES_OUTPUT.read().unwrap();
-    assert_eq!(*reader, PRIMES_OUTPUT_EXPECTED);
+    let collector = mix.device_ref::<LineCollectorIODevice>(18).unwrap();
+    assert_eq!(collector.output, PRIMES_OUTPUT_EXPECTED);
 }